@@ -1,16 +1,15 @@
+use std::rc::Rc;
 use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
 use gloo_timers::callback::Timeout;
 use crate::components::*;
 use crate::modules::*;
 
-const STORAGE_KEY_ZONES: &str = "tz-clock-zones";
-const STORAGE_KEY_THEME: &str = "tz-clock-theme";
-const STORAGE_KEY_MODE: &str = "tz-clock-mode";
-
-fn schedule_minute_update(set_current_utc: WriteSignal<f64>) {
+pub(crate) fn schedule_minute_update(set_current_utc: WriteSignal<f64>) {
     let now = js_sys::Date::new_0();
-    let secs = now.get_utc_seconds() as u32;
-    let ms = now.get_utc_milliseconds() as u32;
+    let secs = now.get_utc_seconds();
+    let ms = now.get_utc_milliseconds();
     let ms_to_next = ((60 - secs) * 1000).saturating_sub(ms).max(100);
 
     let timeout = Timeout::new(ms_to_next, move || {
@@ -20,110 +19,419 @@ fn schedule_minute_update(set_current_utc: WriteSignal<f64>) {
     std::mem::forget(timeout);
 }
 
-fn load_zones() -> ActiveTimezones {
-    let storage = web_sys::window()
-        .and_then(|w| w.local_storage().ok().flatten());
-    if let Some(storage) = storage {
-        if let Ok(Some(raw)) = storage.get_item(STORAGE_KEY_ZONES) {
-            let ids: Vec<&str> = raw.split(',').collect();
-            let zones: Vec<TimezoneEntry> = ids.iter().filter_map(|id| {
-                TIMEZONE_DATABASE.iter().find(|tz| tz.id == *id).cloned()
-            }).collect();
-            if !zones.is_empty() {
-                return ActiveTimezones { zones };
-            }
-        }
-    }
-    ActiveTimezones::default()
+/// The browser's current UTC offset in hours
+fn browser_utc_offset() -> f64 {
+    -js_sys::Date::new_0().get_timezone_offset() / 60.0
 }
 
-fn save_zones(zones: &ActiveTimezones) {
-    let storage = web_sys::window()
-        .and_then(|w| w.local_storage().ok().flatten());
-    if let Some(storage) = storage {
-        let ids: Vec<&str> = zones.zones.iter().map(|z| z.id).collect();
-        let _ = storage.set_item(STORAGE_KEY_ZONES, &ids.join(","));
-    }
+/// The browser's IANA zone as a database entry, or the nearest one when the
+/// database has none
+fn detect_local_zone() -> Option<&'static TimezoneEntry> {
+    let options = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &js_sys::Object::new()).resolved_options();
+    let iana = js_sys::Reflect::get(&options, &"timeZone".into()).ok()?.as_string()?;
+    // Minutes west of UTC; the standard offset is the smaller of January's and July's
+    let year = js_sys::Date::new_0().get_full_year();
+    let minutes_west = |month| js_sys::Date::new_with_year_month_day(year, month, 1).get_timezone_offset();
+    let standard_offset = -minutes_west(0).max(minutes_west(6)) / 60.0;
+    Some(zone_for_iana(&iana, standard_offset))
 }
 
-fn get_storage() -> Option<web_sys::Storage> {
-    web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+/// The UI language matching the browser's preferred language, English otherwise
+fn browser_language() -> Language {
+    web_sys::window()
+        .and_then(|w| w.navigator().language())
+        .and_then(|tag| Language::from_tag(&tag))
+        .unwrap_or_default()
 }
 
-fn load_theme() -> ThemeName {
-    get_storage()
-        .and_then(|s| s.get_item(STORAGE_KEY_THEME).ok().flatten())
-        .and_then(|v| match v.as_str() {
-            "Minimalist" => Some(ThemeName::Minimalist),
-            "Bold" => Some(ThemeName::Bold),
-            "Professional" => Some(ThemeName::Professional),
-            "Playful" => Some(ThemeName::Playful),
-            _ => None,
-        })
-        .unwrap_or(ThemeName::Minimalist)
-}
+/// Id of the `<style>` element holding the page's color variables
+const THEME_STYLE_ID: &str = "tz-clock-theme";
 
-fn save_theme(name: ThemeName) {
-    if let Some(storage) = get_storage() {
-        let _ = storage.set_item(STORAGE_KEY_THEME, name.label());
-    }
+/// Write the palette as CSS variables on the root element. The rule has no
+/// specificity, so a host page's own `:root` or `html` rule overrides a token.
+fn set_document_theme(colors: &impl Palette) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else { return };
+    let style = match document.get_element_by_id(THEME_STYLE_ID) {
+        Some(style) => style,
+        None => {
+            let Ok(style) = document.create_element("style") else { return };
+            style.set_id(THEME_STYLE_ID);
+            // First in <head>, so the page's own stylesheets come after it
+            if let Some(head) = document.head() {
+                let _ = head.insert_before(&style, head.first_child().as_ref());
+            }
+            style
+        }
+    };
+    style.set_text_content(Some(&theme_stylesheet(colors, ":where(:root)")));
 }
 
-fn load_mode() -> Mode {
-    get_storage()
-        .and_then(|s| s.get_item(STORAGE_KEY_MODE).ok().flatten())
-        .and_then(|v| match v.as_str() {
-            "Light" => Some(Mode::Light),
-            "Dark" => Some(Mode::Dark),
-            _ => None,
-        })
-        .unwrap_or(Mode::Dark)
+/// Today's day of the year (1-366) in UTC
+fn utc_day_of_year() -> u32 {
+    let now = js_sys::Date::new_0();
+    let start = js_sys::Date::utc(now.get_utc_full_year() as f64, 0.0);
+    ((now.get_time() - start) / 86_400_000.0).floor() as u32 + 1
 }
 
-fn save_mode(mode: Mode) {
-    if let Some(storage) = get_storage() {
-        let _ = storage.set_item(STORAGE_KEY_MODE, match mode {
-            Mode::Light => "Light",
-            Mode::Dark => "Dark",
-        });
-    }
+pub(crate) fn embed_config() -> Option<EmbedConfig> {
+    let search = web_sys::window()?.location().search().ok()?;
+    parse_embed_query(&search)
 }
 
 #[component]
-pub fn App() -> impl IntoView {
-    // State
-    let (meetings, set_meetings) = signal(Vec::<Meeting>::new());
+pub fn App(opened: OpenedStore) -> impl IntoView {
+    // Storage problems are shown in a notice rather than silently dropped
+    let (storage_error, set_storage_error) = signal(opened.warning);
+    opened.store.on_async_error(Rc::new(move |e| set_storage_error.set(Some(e))));
+    let store = StoredValue::new_local(opened.store);
+    let report = move |result: Result<(), StoreError>| match result {
+        Err(e) => set_storage_error.set(Some(e)),
+        // A later successful write resolves an earlier write failure
+        Ok(()) => {
+            if storage_error.with_untracked(|e| matches!(e, Some(StoreError::QuotaExceeded | StoreError::Failed(_)))) {
+                set_storage_error.set(None);
+            }
+        }
+    };
+
+    let local_zone = detect_local_zone();
+
+    // State: zones, meetings and theme mirror the active workspace
+    let (initial_workspaces, initial_active) = store.with_value(|s| load_workspaces(s.as_ref(), local_zone))
+        .unwrap_or_else(|e| {
+            set_storage_error.set(Some(e));
+            (vec![Workspace::new(1, "Default")], 1)
+        });
+    let initial = initial_workspaces.iter().find(|w| w.id == initial_active).cloned()
+        .unwrap_or_else(|| initial_workspaces[0].clone());
+    let (workspaces, set_workspaces) = signal(initial_workspaces);
+    let (active_workspace, set_active_workspace) = signal(initial_active);
+    let (meetings, set_meetings) = signal(initial.meetings);
     let (selected_slot, set_selected_slot) = signal(None::<SelectedSlot>);
     let (current_utc, set_current_utc) = signal(get_current_utc_hour());
-    let (active_zones, set_active_zones) = signal(load_zones());
-    let (theme_name, set_theme_name) = signal(load_theme());
-    let (mode, set_mode) = signal(load_mode());
+    let (active_zones, set_active_zones) = signal(initial.zones);
+    let (theme_name, set_theme_name) = signal(initial.theme);
+    let (reference_zone, set_reference_zone) = signal(initial.reference);
+    let (custom_themes, set_custom_themes) = signal(
+        store.with_value(|s| load_custom_themes(s.as_ref())).unwrap_or_else(|e| {
+            set_storage_error.set(Some(e));
+            Vec::new()
+        })
+    );
+    // Custom theme open in the editor; previewed everywhere until saved or discarded
+    let (theme_draft, set_theme_draft) = signal(None::<ThemeDraft>);
+    let (mode_setting, set_mode_setting) = signal(store.with_value(|s| load_mode(s.as_ref())).unwrap_or_default());
+    let (hour_cycle, set_hour_cycle) = signal(
+        store.with_value(|s| load_hour_cycle(s.as_ref())).ok().flatten().unwrap_or_else(browser_hour_cycle)
+    );
+    let (layout, set_layout) = signal(store.with_value(|s| load_layout(s.as_ref())).unwrap_or_default());
+    let (hatch, set_hatch) = signal(store.with_value(|s| load_hatch(s.as_ref())).unwrap_or(false));
+    let (language, set_language) = signal(
+        store.with_value(|s| load_language(s.as_ref())).ok().flatten().unwrap_or_else(browser_language)
+    );
+    let lang: Signal<Language> = language.into();
+
+    // The operating system's color scheme, followed live for the System setting
+    let dark_query = web_sys::window().and_then(|w| w.match_media("(prefers-color-scheme: dark)").ok().flatten());
+    let (system_dark, set_system_dark) = signal(dark_query.as_ref().is_none_or(|q| q.matches()));
+    if let Some(query) = dark_query {
+        let on_change = Closure::<dyn Fn(web_sys::MediaQueryListEvent)>::new(move |ev: web_sys::MediaQueryListEvent| {
+            set_system_dark.set(ev.matches());
+        });
+        let _ = query.add_event_listener_with_callback("change", on_change.as_ref().unchecked_ref());
+        // The app lives as long as the page
+        on_change.forget();
+    }
+
+    // Derived: time formatting for every label, in the UI language's conventions
+    let time_format: Signal<TimeFormat> = Memo::new(move |_| TimeFormat::for_locale(hour_cycle.get(), language.get().code())).into();
+
+    // Derived: the zone the dial is anchored to. The browser offset is re-read
+    // each minute so a local DST change is picked up.
+    let local_offset = Memo::new(move |_| {
+        current_utc.track();
+        browser_utc_offset()
+    });
+    let reference: Signal<Reference> = Memo::new(move |_| {
+        active_zones.with(|z| z.reference(reference_zone.get(), local_offset.get()))
+    }).into();
+
+    // Derived: the zone behind the reference, for its coordinates and DST rules
+    let reference_tz = Memo::new(move |_| match reference_zone.get() {
+        ReferenceZone::Local => local_zone,
+        _ => reference.get().zone_id.and_then(zone_by_id),
+    });
 
-    // Derived: active theme colors
-    let theme = Signal::derive(move || get_theme(theme_name.get(), mode.get()));
+    // Derived: sunrise and sunset today in the reference zone, for the Sunset setting
+    let daylight = Memo::new(move |_| {
+        current_utc.track();
+        let r = reference.get();
+        let (lat, lon) = reference_tz.get().map(zone_coordinates).unwrap_or((0.0, r.utc_offset * 15.0));
+        daylight(utc_day_of_year(), lat, lon)
+    });
+
+    // Derived: the light/dark mode actually shown
+    let mode: Signal<Mode> = Memo::new(move |_| match mode_setting.get() {
+        ModeSetting::Light => Mode::Light,
+        ModeSetting::Dark => Mode::Dark,
+        ModeSetting::System => if system_dark.get() { Mode::Dark } else { Mode::Light },
+        ModeSetting::Sunset => if daylight.get().is_dark(current_utc.get()) { Mode::Dark } else { Mode::Light },
+    }).into();
+
+    // A theme or mode change only rewrites the page's color variables; an
+    // open draft is previewed in place of the active theme
+    Effect::new(move || {
+        let mode = mode.get();
+        theme_draft.with(|d| match d {
+            Some(d) => set_document_theme(d.palette(mode)),
+            None => set_document_theme(&custom_themes.with(|c| resolve_theme(theme_name.get(), mode, c))),
+        });
+    });
 
     // Minute-aligned timer: updates at each minute boundary
     schedule_minute_update(set_current_utc);
 
-    // Persist zones and clear selected slot when active zones change
+    // Clear selected slot when active zones change
     Effect::new(move || {
-        let zones = active_zones.get();
-        save_zones(&zones);
+        active_zones.track();
         set_selected_slot.set(None);
     });
 
-    // Persist theme and mode
-    Effect::new(move || save_theme(theme_name.get()));
-    Effect::new(move || save_mode(mode.get()));
+    // Write edits back into the active workspace, and persist workspaces and mode
+    Effect::new(move || {
+        let zones = active_zones.get();
+        let meetings = meetings.get();
+        let theme = theme_name.get();
+        let reference = reference_zone.get();
+        let id = active_workspace.get_untracked();
+        set_workspaces.update(|ws| {
+            if let Some(w) = ws.iter_mut().find(|w| w.id == id) {
+                w.zones = zones;
+                w.meetings = meetings;
+                w.theme = theme;
+                w.reference = reference;
+            }
+        });
+    });
+    Effect::new(move || {
+        let (ws, active) = (workspaces.get(), active_workspace.get());
+        report(store.with_value(|s| save_workspaces(s.as_ref(), &ws, active)));
+    });
+    Effect::new(move || {
+        let mode = mode_setting.get();
+        report(store.with_value(|s| save_mode(s.as_ref(), mode)));
+    });
+    Effect::new(move || {
+        let themes = custom_themes.get();
+        report(store.with_value(|s| save_custom_themes(s.as_ref(), &themes)));
+    });
+    Effect::new(move || {
+        let cycle = hour_cycle.get();
+        report(store.with_value(|s| save_hour_cycle(s.as_ref(), cycle)));
+    });
+    Effect::new(move || {
+        let layout = layout.get();
+        report(store.with_value(|s| save_layout(s.as_ref(), layout)));
+    });
+    Effect::new(move || {
+        let hatch = hatch.get();
+        report(store.with_value(|s| save_hatch(s.as_ref(), hatch)));
+    });
+
+    Effect::new(move || {
+        let language = language.get();
+        report(store.with_value(|s| save_language(s.as_ref(), language)));
+        // Screen readers and hyphenation follow the document language
+        if let Some(root) = document().document_element() {
+            let _ = root.set_attribute("lang", language.code());
+        }
+    });
+
+    // Undo/redo: every change to the undoable state records the state it replaced
+    let history = RwSignal::new(History::default());
+    let (toast, set_toast) = signal(None::<Removal>);
+    let last_snapshot = StoredValue::new(AppSnapshot {
+        zones: active_zones.get_untracked(),
+        meetings: meetings.get_untracked(),
+        theme: theme_name.get_untracked(),
+        mode: mode_setting.get_untracked(),
+    });
+    Effect::new(move || {
+        let current = AppSnapshot {
+            zones: active_zones.get(),
+            meetings: meetings.get(),
+            theme: theme_name.get(),
+            mode: mode_setting.get(),
+        };
+        let previous = last_snapshot.get_value();
+        // Restores from undo/redo update last_snapshot first, so they land here
+        if current == previous {
+            return;
+        }
+        if let Some(removal) = previous.destructive_change(&current) {
+            set_toast.set(Some(removal));
+            history.update(|h| h.checkpoint(previous));
+        } else {
+            history.update(|h| h.record(previous, js_sys::Date::now()));
+        }
+        last_snapshot.set_value(current);
+    });
+
+    let restore = move |snapshot: AppSnapshot| {
+        last_snapshot.set_value(snapshot.clone());
+        set_active_zones.set(snapshot.zones);
+        set_meetings.set(snapshot.meetings);
+        set_theme_name.set(snapshot.theme);
+        set_mode_setting.set(snapshot.mode);
+    };
+    let undo = Callback::new(move |()| {
+        let current = last_snapshot.get_value();
+        if let Some(previous) = history.try_update(|h| h.undo(current)).flatten() {
+            restore(previous);
+            set_toast.set(None);
+        }
+    });
+    let redo = Callback::new(move |()| {
+        let current = last_snapshot.get_value();
+        if let Some(next) = history.try_update(|h| h.redo(current)).flatten() {
+            restore(next);
+        }
+    });
+    // Loading a workspace replaces the mirrored state; history does not cross workspaces
+    let load_workspace = move |w: Workspace| {
+        history.set(History::default());
+        set_toast.set(None);
+        last_snapshot.set_value(AppSnapshot {
+            zones: w.zones.clone(),
+            meetings: w.meetings.clone(),
+            theme: w.theme,
+            mode: mode_setting.get_untracked(),
+        });
+        set_active_zones.set(w.zones);
+        set_meetings.set(w.meetings);
+        set_theme_name.set(w.theme);
+        set_reference_zone.set(w.reference);
+    };
+    Effect::new(move |previous: Option<u32>| {
+        let id = active_workspace.get();
+        if previous.is_some_and(|p| p != id) {
+            if let Some(w) = workspaces.with_untracked(|ws| ws.iter().find(|w| w.id == id).cloned()) {
+                load_workspace(w);
+            }
+        }
+        id
+    });
+
+    // Importing a configuration file; the active workspace is reloaded since a merge may have changed it
+    let on_import = Callback::new(move |(doc, import_mode): (ConfigDocument, ImportMode)| {
+        let merged = apply_import(&workspaces.get_untracked(), doc.workspaces, import_mode);
+        let current = active_workspace.get_untracked();
+        let target = merged.iter().find(|w| w.id == current).unwrap_or(&merged[0]).clone();
+        set_workspaces.set(merged);
+        if let Some(m) = doc.mode {
+            set_mode_setting.set(m);
+        }
+        set_active_workspace.set(target.id);
+        load_workspace(target);
+    });
+
+    // Cross-tab sync: adopt what other tabs save. The stored workspaces are the
+    // latest write from any tab and replace ours wholesale; the active
+    // workspace stays per tab. Remote edits to the active workspace drop this
+    // tab's undo history, which would otherwise write stale state back over them.
+    let storage_listener = window_event_listener(leptos::ev::storage, move |ev| {
+        let Some(key) = ev.key() else { return };
+        let Some(raw) = ev.new_value() else { return };
+        match key.as_str() {
+            STORAGE_KEY_WORKSPACES => {
+                let Some(remote) = workspaces_from_json(&raw) else { return };
+                let active = active_workspace.get_untracked();
+                let Some(update) = workspaces.with_untracked(|ws| reconcile_remote_workspaces(ws, active, remote)) else { return };
+                set_workspaces.set(update.workspaces);
+                if update.active != active {
+                    // Our workspace was deleted elsewhere; switching loads the fallback
+                    set_active_workspace.set(update.active);
+                } else if let Some(w) = update.reload {
+                    history.update(History::adopt_remote);
+                    set_toast.set(None);
+                    set_reference_zone.set(w.reference);
+                    restore(AppSnapshot { zones: w.zones, meetings: w.meetings, theme: w.theme, mode: mode_setting.get_untracked() });
+                }
+            }
+            STORAGE_KEY_HOUR_CYCLE => {
+                if let Some(cycle) = HourCycle::from_label(&raw) {
+                    set_hour_cycle.set(cycle);
+                }
+            }
+            STORAGE_KEY_HATCH => set_hatch.set(raw == "on"),
+            STORAGE_KEY_LAYOUT => {
+                if let Some(l) = ClockLayout::from_label(&raw) {
+                    set_layout.set(l);
+                }
+            }
+            STORAGE_KEY_THEMES => {
+                if let Some(themes) = custom_themes_from_json(&raw) {
+                    set_custom_themes.set(themes);
+                }
+            }
+            STORAGE_KEY_LANGUAGE => {
+                if let Some(l) = Language::from_code(&raw) {
+                    set_language.set(l);
+                }
+            }
+            STORAGE_KEY_MODE => {
+                if let Some(m) = ModeSetting::from_label(&raw).filter(|m| *m != mode_setting.get_untracked()) {
+                    last_snapshot.update_value(|s| s.mode = m);
+                    set_mode_setting.set(m);
+                }
+            }
+            _ => {}
+        }
+    });
+    on_cleanup(move || storage_listener.remove());
+
+    let can_undo = Signal::derive(move || history.with(History::can_undo));
+    let can_redo = Signal::derive(move || history.with(History::can_redo));
+
+    // Ctrl+Z / Ctrl+Shift+Z (Cmd on macOS); text fields keep their native undo
+    let keydown = window_event_listener(leptos::ev::keydown, move |ev| {
+        if !(ev.ctrl_key() || ev.meta_key()) {
+            return;
+        }
+        let in_text_field = ev.target()
+            .and_then(|t| wasm_bindgen::JsCast::dyn_into::<web_sys::Element>(t).ok())
+            .is_some_and(|el| matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA"));
+        if in_text_field {
+            return;
+        }
+        match ev.key().to_ascii_lowercase().as_str() {
+            "z" if ev.shift_key() => redo.run(()),
+            "z" => undo.run(()),
+            "y" => redo.run(()),
+            _ => return,
+        }
+        ev.prevent_default();
+    });
+    on_cleanup(move || keydown.remove());
 
     view! {
-        <div style=move || format!(
+        <div style=format!(
             "min-height: 100vh; display: flex; flex-direction: column; overflow: auto; background-color: {}; transition: all 0.3s ease",
-            theme.get().background
+            THEME_VARS.background
         )>
             <Header
                 active_zones=active_zones
-                theme=theme
+                workspaces=workspaces
+                set_workspaces=set_workspaces
+                active_workspace=active_workspace
+                set_active_workspace=set_active_workspace
+                can_undo=can_undo
+                can_redo=can_redo
+                on_undo=undo
+                on_redo=redo
+                layout=layout
+                set_layout=set_layout
+                lang=lang
             />
 
             <TimezoneConfigurator
@@ -131,28 +439,144 @@ pub fn App() -> impl IntoView {
                 set_active_zones=set_active_zones
                 theme_name=theme_name
                 set_theme_name=set_theme_name
+                custom_themes=custom_themes
+                set_custom_themes=set_custom_themes
+                theme_draft=theme_draft
+                set_theme_draft=set_theme_draft
                 mode=mode
-                set_mode=set_mode
-                theme=theme
+                mode_setting=mode_setting
+                set_mode_setting=set_mode_setting
+                daylight=daylight
+                reference=reference
+                reference_zone=reference_zone
+                set_reference_zone=set_reference_zone
+                local_zone=local_zone.map(|tz| tz.id)
+                hour_cycle=hour_cycle
+                set_hour_cycle=set_hour_cycle
+                hatch=hatch
+                set_hatch=set_hatch
+                time_format=time_format
+                language=language
+                set_language=set_language
+                workspaces=workspaces
+                on_import=on_import
+            />
+
+            <ClockChangeNotice
+                meetings=meetings
+                active_zones=active_zones
+                current_utc=current_utc
+                time_format=time_format
+                lang=lang
             />
 
             <div style="flex: 1; display: flex; flex-wrap: wrap; align-items: center; justify-content: center; gap: 32px; padding: 32px">
-                <div style="flex-shrink: 0; width: 100%; max-width: 400px">
-                    <Clock
-                        meetings=meetings
-                        set_selected_slot=set_selected_slot
-                        current_utc=current_utc
-                        active_zones=active_zones
-                        theme=theme
-                    />
-                </div>
+                {move || match layout.get() {
+                    ClockLayout::Dial => view! {
+                        <div style="flex-shrink: 0; width: 100%; max-width: 400px">
+                            <Clock
+                                meetings=meetings
+                                set_selected_slot=set_selected_slot
+                                current_utc=current_utc
+                                active_zones=active_zones
+                                set_meetings=set_meetings
+                                set_active_zones=set_active_zones
+                                reference=reference
+                                local_zone=local_zone.map(|tz| tz.id)
+                                time_format=time_format
+                                lang=lang
+                                hatch=Signal::from(hatch)
+                            />
+                        </div>
+                    }.into_any(),
+                    ClockLayout::Timeline => view! {
+                        <div style="width: 100%; max-width: 960px">
+                            <Timeline
+                                meetings=meetings
+                                selected_slot=selected_slot
+                                set_selected_slot=set_selected_slot
+                                current_utc=current_utc
+                                active_zones=active_zones
+                                reference=reference
+                                local_zone=local_zone.map(|tz| tz.id)
+                                time_format=time_format
+                                lang=lang
+                                hatch=Signal::from(hatch)
+                            />
+                        </div>
+                    }.into_any(),
+                    ClockLayout::Week => view! {
+                        <div style="width: 100%; max-width: 960px">
+                            <WeekView
+                                meetings=meetings
+                                selected_slot=selected_slot
+                                set_selected_slot=set_selected_slot
+                                current_utc=current_utc
+                                active_zones=active_zones
+                                reference=reference
+                                reference_tz=reference_tz
+                                time_format=time_format
+                                lang=lang
+                            />
+                        </div>
+                    }.into_any(),
+                }}
 
                 <InfoPanels
                     meetings=meetings
                     set_meetings=set_meetings
                     selected_slot=selected_slot
+                    set_selected_slot=set_selected_slot
+                    active_zones=active_zones
+                    reference=reference
+                    time_format=time_format
+                    lang=lang
+                />
+            </div>
+
+            <OverlapAnalytics
+                active_zones=active_zones
+                reference=reference
+                reference_tz=reference_tz
+                lang=lang
+            />
+
+            <UndoToast message=toast set_message=set_toast on_undo=undo lang=lang />
+            <StorageNotice error=storage_error set_error=set_storage_error lang=lang />
+        </div>
+    }
+}
+
+/// Compact clock for iframes: no header, configurator or panels, and
+/// configuration comes from the query string only, so the host page's
+/// localStorage is never read or written.
+#[component]
+pub fn EmbedApp(config: EmbedConfig) -> impl IntoView {
+    let (meetings, _) = signal(Vec::<Meeting>::new());
+    let (_, set_selected_slot) = signal(None::<SelectedSlot>);
+    let (current_utc, set_current_utc) = signal(get_current_utc_hour());
+    let (active_zones, _) = signal(config.zones.map(ActiveTimezones::new).unwrap_or_default());
+    let theme_name = config.theme.unwrap_or(ThemeName::Minimalist);
+    let mode = config.mode.unwrap_or(Mode::Dark);
+    set_document_theme(get_theme(theme_name, mode));
+
+    schedule_minute_update(set_current_utc);
+
+    let background = if config.transparent { "transparent" } else { THEME_VARS.background };
+    let max_width = config.size.map(|s| format!("{}px", s)).unwrap_or_else(|| "100%".to_string());
+
+    view! {
+        <div style=format!(
+            "min-height: 100vh; display: flex; align-items: center; justify-content: center; background-color: {}",
+            background
+        )>
+            <div style=format!("width: 100%; max-width: {}", max_width)>
+                <Clock
+                    meetings=meetings
+                    set_selected_slot=set_selected_slot
+                    current_utc=current_utc
                     active_zones=active_zones
-                    theme=theme
+                    hatch=Signal::stored(config.hatch)
                 />
             </div>
         </div>
@@ -1,59 +1,53 @@
 use leptos::prelude::*;
 use crate::modules::*;
 
+/// Half the width of the ring list, which fits inside the center circle
+const LIST_HALF_WIDTH: f64 = 38.0;
+
 #[component]
 pub fn CenterDisplay(
     current_utc: ReadSignal<f64>,
     active_zones: ReadSignal<ActiveTimezones>,
-    theme: ThemeColors,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
 ) -> impl IntoView {
-    let center_bg = theme.center_circle_bg;
-    let text_muted = theme.text_muted;
-
-    view! {
-        {move || {
-            let zones = active_zones.get();
-            let n = zones.zones.len();
-            let now = js_sys::Date::new_0();
-            let utc_min = now.get_utc_minutes() as f64;
-            let utc_hour = current_utc.get();
+    let ring_count = Memo::new(move |_| active_zones.with(|z| z.zones().len()));
+    let font_size = move || {
+        let n = ring_count.get();
+        if n <= 3 { 11 } else if n == 4 { 9 } else { 8 }
+    };
 
-            let first_y = CY - 8.0;
-            let last_y = CY + 28.0;
-            let spacing = if n > 1 { (last_y - first_y) / (n as f64 - 1.0) } else { 0.0 };
-            let font_size = if n <= 3 { 11 } else if n == 4 { 9 } else { 8 };
+    let line_text = move |i: usize| {
+        active_zones.with(|z| z.zones().get(i).map(|tz| {
+            let local = utc_to_local(current_utc.get(), tz.utc_offset);
+            format!("{} {}", time_format.with(|f| f.time(local)), tz.short_name)
+        })).unwrap_or_default()
+    };
 
-            let time_lines: Vec<_> = zones.zones.iter().enumerate().map(|(i, tz)| {
-                let h = utc_to_local(utc_hour, tz.utc_offset);
-                let hour_part = h.floor() as u32 % 24;
-                let frac = tz.utc_offset % 1.0;
-                let display_mins = if frac.abs() < 0.01 {
-                    format!("{:02}", utc_min as u32)
-                } else {
-                    let local_min = (utc_min + frac * 60.0 + 60.0) % 60.0;
-                    format!("{:02}", local_min.floor() as u32)
-                };
-                let text = format!("{:02}:{} {}", hour_part, display_mins, tz.short_name);
-                let color = theme.ring_text_colors[i.min(4)];
-                let y = if n == 1 { CY + 6.0 } else { first_y + spacing * i as f64 };
-                (text, color, y)
-            }).collect();
-
-            view! {
-                <g>
-                    <circle cx=CX cy=CY r=CENTER_R fill=center_bg stroke="#ec4899" stroke-width="3" />
-                    <text x=CX y={CY - 22.0} text-anchor="middle" font-size="9" fill=text_muted>
-                        "NOW"
-                    </text>
-                    {time_lines.into_iter().map(|(text, color, y)| {
-                        view! {
-                            <text x=CX y=y text-anchor="middle" font-size=font_size font-weight="600" fill=color>
-                                {text}
-                            </text>
-                        }
-                    }).collect_view()}
-                </g>
-            }
-        }}
+    // Read out through the clock's live region instead
+    view! {
+        <g aria-hidden="true">
+            <circle cx=CX cy=CY r=CENTER_R style=format!("fill: {}; stroke: {}", THEME_VARS.center_circle_bg, THEME_VARS.now_line) stroke-width="3" />
+            <text x=CX y={CY - 22.0} text-anchor="middle" font-size="9" style=format!("fill: {}", THEME_VARS.text_muted)>
+                {move || lang.get().t(Msg::Now)}
+            </text>
+            // One line per ring, scrolling once they outgrow the circle
+            <foreignObject x={CX - LIST_HALF_WIDTH} y={CY - 14.0} width={2.0 * LIST_HALF_WIDTH} height="52">
+                <div style=move || format!(
+                    "height: 100%; overflow-y: auto; scrollbar-width: thin; display: flex; flex-direction: column; align-items: center; justify-content: safe center; font-size: {}px; font-weight: 600; line-height: 1.25; white-space: nowrap",
+                    font_size()
+                )>
+                    <For
+                        each=move || 0..ring_count.get()
+                        key=|i| *i
+                        let:i
+                    >
+                        <div style=move || format!("color: {}", THEME_VARS.ring_text(active_zones.with(|z| z.palette_index(i))))>
+                            {move || line_text(i)}
+                        </div>
+                    </For>
+                </div>
+            </foreignObject>
+        </g>
     }
 }
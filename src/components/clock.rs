@@ -1,76 +1,224 @@
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
 use crate::modules::*;
 use super::clock_segment::ClockSegment;
 use super::now_highlight::NowHighlight;
 use super::center_display::CenterDisplay;
 
+/// Id of the stripe pattern segments use for hatched working hours
+pub(crate) const HATCH_PATTERN_ID: &str = "tz-clock-working-hatch";
+
+/// `attribute` of the nearest element carrying it under the pointer
+fn attribute_at(ev: &leptos::ev::PointerEvent, attribute: &str) -> Option<String> {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.element_from_point(ev.client_x() as f32, ev.client_y() as f32))
+        .and_then(|el| el.closest(&format!("[{}]", attribute)).ok().flatten())
+        .and_then(|el| el.get_attribute(attribute))
+}
+
 #[component]
 pub fn Clock(
     meetings: ReadSignal<Vec<Meeting>>,
     set_selected_slot: WriteSignal<Option<SelectedSlot>>,
     current_utc: ReadSignal<f64>,
     active_zones: ReadSignal<ActiveTimezones>,
-    theme: Signal<&'static ThemeColors>,
+    /// Enables dragging meeting markers to another hour when provided.
+    #[prop(optional)]
+    set_meetings: Option<WriteSignal<Vec<Meeting>>>,
+    /// Enables dragging a ring onto another to reorder them when provided.
+    #[prop(optional)]
+    set_active_zones: Option<WriteSignal<ActiveTimezones>>,
+    /// Zone the dial is anchored to; the first ring when not provided.
+    #[prop(optional)]
+    reference: Option<Signal<Reference>>,
+    /// Zone id of the viewer's own zone, marked "you are here" on its ring.
+    #[prop(optional_no_strip)]
+    local_zone: Option<&'static str>,
+    /// 24-hour labels when not provided.
+    #[prop(optional)]
+    time_format: Option<Signal<TimeFormat>>,
+    /// English when not provided.
+    #[prop(optional)]
+    lang: Option<Signal<Language>>,
+    /// Stripes over working hours in addition to their color; off when not provided.
+    #[prop(optional)]
+    hatch: Option<Signal<bool>>,
 ) -> impl IntoView {
+    let hatch = hatch.unwrap_or_else(|| Signal::stored(false));
+    let time_format = time_format.unwrap_or_else(|| Signal::stored(TimeFormat::default()));
+    let lang = lang.unwrap_or_else(|| Signal::stored(Language::default()));
+    // The SVG is built once. Rings are keyed by index so adding or removing a
+    // zone only creates/drops that ring; everything else updates in place
+    // through the memos below.
+    let ring_count = Memo::new(move |_| active_zones.with(|z| z.zones().len()));
+    let geos = Memo::new(move |_| compute_ring_geometries(ring_count.get()));
+    let ref_offset = Memo::new(move |_| match reference {
+        Some(reference) => reference.get().utc_offset,
+        None => active_zones.with(|z| z.zones()[0].utc_offset),
+    });
+
+    // Ids of the meetings whose marker is being dragged. The drop is resolved
+    // on the window so it also works for touch, where the pointer stays
+    // captured by the marker it started on.
+    let dragging = RwSignal::new(None::<Vec<u32>>);
+    if let Some(set_meetings) = set_meetings {
+        let on_up = window_event_listener(leptos::ev::pointerup, move |ev| {
+            let Some(ids) = dragging.get_untracked() else { return };
+            dragging.set(None);
+            let target_hour = attribute_at(&ev, "data-utc-hour").and_then(|h| h.parse::<u32>().ok());
+            if let Some(utc_hour) = target_hour {
+                set_meetings.update(|ms| reschedule_meetings(ms, &ids, utc_hour));
+                set_selected_slot.set(Some(active_zones.with_untracked(|z| SelectedSlot::at(utc_hour, z))));
+            }
+        });
+        let on_cancel = window_event_listener(leptos::ev::pointercancel, move |_| dragging.set(None));
+        on_cleanup(move || {
+            on_up.remove();
+            on_cancel.remove();
+        });
+    }
+    let draggable = set_meetings.is_some();
+
+    // Keyboard focus on the dial, shared so arrow keys can move it between rings
+    let focus = RwSignal::new((0usize, 0u32));
+
+    // (ring pressed, ring under the pointer) while reordering rings
+    let ring_drag = RwSignal::new(None::<(usize, usize)>);
+    if let Some(set_active_zones) = set_active_zones {
+        let on_up = window_event_listener(leptos::ev::pointerup, move |ev| {
+            let Some((from, _)) = ring_drag.get_untracked() else { return };
+            ring_drag.set(None);
+            let target = attribute_at(&ev, "data-ring").and_then(|r| r.parse::<usize>().ok());
+            if let Some(to) = target.filter(|&to| to != from) {
+                set_active_zones.update(|z| z.move_zone(from, to));
+                focus.update(|f| f.0 = to);
+            }
+        });
+        let on_cancel = window_event_listener(leptos::ev::pointercancel, move |_| ring_drag.set(None));
+        on_cleanup(move || {
+            on_up.remove();
+            on_cancel.remove();
+        });
+    }
+    let is_dragging = move || dragging.with(Option::is_some) || ring_drag.with(|d| d.is_some_and(|(from, over)| from != over));
+
+    // Every zone's current time, announced to screen readers when focus
+    // enters the dial rather than on each tick, which would interrupt them
+    let (announcement, set_announcement) = signal(String::new());
+    let now_text = move || {
+        let l = lang.get_untracked();
+        let utc = current_utc.get_untracked();
+        let times = active_zones.with_untracked(|z| z.zones().iter().map(|tz| {
+            format!("{} {}", time_format.with_untracked(|f| f.time(utc_to_local(utc, tz.utc_offset))), l.zone_name(tz))
+        }).collect::<Vec<_>>().join(", "));
+        l.fill(Msg::NowAt, &[&times])
+    };
+    let on_focusin = move |ev: leptos::ev::FocusEvent| {
+        let node = |t: Option<web_sys::EventTarget>| t.and_then(|t| t.dyn_into::<web_sys::Node>().ok());
+        let from_inside = node(ev.current_target())
+            .zip(node(ev.related_target()))
+            .is_some_and(|(dial, from)| dial.contains(Some(&from)));
+        if !from_inside {
+            set_announcement.set(now_text());
+        }
+    };
+
     view! {
-        {move || {
-            let t = *theme.get();
-            let zones = active_zones.get();
-            let n = zones.zones.len();
-            let geos = compute_ring_geometries(n);
-            let ref_offset = zones.zones[0].utc_offset;
+        <svg
+            width="100%"
+            height="auto"
+            viewBox="0 0 400 400"
+            class=move || if is_dragging() { "drop-shadow-lg dragging" } else { "drop-shadow-lg" }
+            style="max-width: 600px; margin: 0 auto; display: block"
+            role="group"
+            aria-label=move || lang.get().t(Msg::DialLabel)
+            on:focusin=on_focusin
+        >
+            <defs>
+                <pattern id=HATCH_PATTERN_ID width="6" height="6" patternUnits="userSpaceOnUse" patternTransform="rotate(45)">
+                    <line x1="0" y1="0" x2="0" y2="6" style=format!("stroke: {}", THEME_VARS.working_hours_hatch) stroke-width="2" />
+                </pattern>
+            </defs>
 
-            view! {
-                <svg
-                    width="100%"
-                    height="auto"
-                    viewBox="0 0 400 400"
-                    class="drop-shadow-lg"
-                    style="max-width: 600px; margin: 0 auto; display: block"
-                >
-                    // Background circle
-                    <circle cx=CX cy=CY r=BG_R fill=t.svg_bg stroke=t.svg_border stroke-width="2" />
+            // Background circle
+            <circle
+                cx=CX
+                cy=CY
+                r=BG_R
+                style=format!("fill: {}; stroke: {}", THEME_VARS.svg_bg, THEME_VARS.svg_border)
+                stroke-width="2"
+            />
 
-                    // Render all ring segments dynamically
-                    {geos.iter().enumerate().flat_map(|(ring_idx, geo)| {
-                        let tz = &zones.zones[ring_idx];
-                        let tz_offset = tz.utc_offset;
-                        let zones_clone = zones.zones.clone();
-                        let outer_r = geo.outer_r;
-                        let inner_r = geo.inner_r;
-                        (0u32..24).map(move |h| {
-                            let zones_for_segment = zones_clone.clone();
-                            view! {
+            // One group of 24 segments per ring
+            <For
+                each=move || 0..ring_count.get()
+                key=|ring_idx| *ring_idx
+                let:ring_idx
+            >
+                {
+                    let geo = Memo::new(move |_| geos.with(|g| g.get(ring_idx).copied()));
+                    let tz_offset = Memo::new(move |_| {
+                        active_zones.with(|z| z.zones().get(ring_idx).map(|tz| tz.utc_offset).unwrap_or(0.0))
+                    });
+                    let work_hours = Memo::new(move |_| {
+                        active_zones.with(|z| z.zones().get(ring_idx).map(|tz| (tz.work_start, tz.work_end)).unwrap_or((WORK_START, WORK_END)))
+                    });
+                    view! {
+                        <g>
+                            {(0u32..24).map(|h| view! {
                                 <ClockSegment
                                     hour=h
-                                    outer_r=outer_r
-                                    inner_r=inner_r
                                     ring_index=ring_idx
+                                    geometry=geo
                                     tz_offset=tz_offset
+                                    work_hours=work_hours
                                     ref_offset=ref_offset
-                                    theme=t
+                                    time_format=time_format
+                                    lang=lang
                                     meetings=meetings
                                     set_selected=set_selected_slot
-                                    active_zones=zones_for_segment
+                                    active_zones=active_zones
+                                    dragging=draggable.then_some(dragging)
+                                    focus=focus
+                                    ring_drag=set_active_zones.is_some().then_some(ring_drag)
+                                    hatch=hatch
                                 />
-                            }
-                        }).collect::<Vec<_>>()
-                    }).collect_view()}
+                            }).collect_view()}
+                        </g>
+                    }
+                }
+            </For>
 
-                    // Ring dividers (N-1 dashed circles)
-                    {geos.iter().take(n.saturating_sub(1)).map(|geo| {
-                        view! {
-                            <circle cx=CX cy=CY r=geo.inner_r fill="none" stroke=t.ring_divider stroke-width="1" stroke-dasharray="2,2" />
-                        }
-                    }).collect_view()}
+            // Ring dividers (N-1 dashed circles)
+            <For
+                each=move || 0..ring_count.get().saturating_sub(1)
+                key=|ring_idx| *ring_idx
+                let:ring_idx
+            >
+                <circle
+                    cx=CX
+                    cy=CY
+                    r=move || geos.with(|g| g.get(ring_idx).map(|geo| geo.inner_r).unwrap_or(0.0))
+                    fill="none"
+                    style=format!("stroke: {}", THEME_VARS.ring_divider)
+                    stroke-width="1"
+                    stroke-dasharray="2,2"
+                />
+            </For>
 
-                    // Now highlight
-                    <NowHighlight current_utc=current_utc active_zones=active_zones theme=t />
+            // Now highlight
+            <NowHighlight current_utc=current_utc active_zones=active_zones ref_offset=ref_offset local_zone=local_zone lang=lang />
 
-                    // Center display
-                    <CenterDisplay current_utc=current_utc active_zones=active_zones theme=t />
-                </svg>
-            }
-        }}
+            // Center display
+            <CenterDisplay current_utc=current_utc active_zones=active_zones time_format=time_format lang=lang />
+        </svg>
+        <div
+            aria-live="polite"
+            aria-atomic="true"
+            style="position: absolute; width: 1px; height: 1px; overflow: hidden; clip: rect(0 0 0 0); white-space: nowrap"
+        >
+            {announcement}
+        </div>
     }
 }
@@ -0,0 +1,95 @@
+use leptos::prelude::*;
+use crate::modules::*;
+
+/// Countdown to the next clock changes in the active zones, with the
+/// meetings they move. Each change can be dismissed until the page reloads.
+#[component]
+pub fn ClockChangeNotice(
+    meetings: ReadSignal<Vec<Meeting>>,
+    active_zones: ReadSignal<ActiveTimezones>,
+    current_utc: ReadSignal<f64>,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    let t = THEME_VARS;
+    // (zone id, instant) of the changes already dismissed
+    let dismissed = RwSignal::new(Vec::<(&'static str, i64)>::new());
+    let key = |w: &ClockChangeWarning| (w.zone_id, w.change.at.round() as i64);
+
+    let warnings = Memo::new(move |_| {
+        current_utc.track();
+        let now = now_epoch_hours();
+        let all = active_zones.with(|z| meetings.with(|ms| clock_change_warnings(z.zones(), ms, now)));
+        let hidden = dismissed.get();
+        all.into_iter()
+            .filter(|w| !hidden.contains(&key(w)))
+            .map(|w| (w, now))
+            .collect::<Vec<_>>()
+    });
+
+    view! {
+        {move || {
+            let list = warnings.get();
+            if list.is_empty() {
+                return None;
+            }
+            let l = lang.get();
+            let tf = time_format.get();
+            let zones = active_zones.get();
+            let all_meetings = meetings.get();
+
+            let items = list.into_iter().filter_map(|(w, now)| {
+                let tz = zones.zones().iter().find(|tz| tz.id == w.zone_id)?;
+                let shift = l.shift(w.change.to - w.change.from);
+                let days = ((w.change.at - now) / 24.0).floor().max(0.0) as usize;
+                let headline = if days == 0 {
+                    l.fill(Msg::ClockChangeToday, &[l.zone_name(tz), &shift])
+                } else {
+                    l.fill(Msg::ClockChangeIn, &[l.zone_name(tz), &shift, &l.count(Count::Days, days)])
+                };
+                let lines = w.meetings.iter().filter_map(|s| {
+                    let m = all_meetings.iter().find(|m| m.id == s.meeting_id)?;
+                    let mut line = l.fill(Msg::MeetingShift, &[&m.title, &tf.time(m.utc_hour as f64), &tf.time(s.local_after)]);
+                    if let Some(inside) = s.overlap_after {
+                        line.push_str(&format!(" \u{2014} {}", l.t(if inside { Msg::JoinsOverlap } else { Msg::LeavesOverlap })));
+                    }
+                    let color = match s.overlap_after {
+                        Some(false) => t.warning_text_dark,
+                        _ => t.warning_text,
+                    };
+                    Some(view! { <li style=format!("color: {}", color)>{line}</li> })
+                }).collect_view();
+                let item_key = key(&w);
+
+                Some(view! {
+                    <li style="display: flex; justify-content: space-between; align-items: flex-start; gap: 12px">
+                        <div>
+                            <strong style=format!("color: {}", t.warning_text_dark)>{headline}</strong>
+                            <ul style="list-style: disc; padding-left: 18px; margin-top: 2px">{lines}</ul>
+                        </div>
+                        <button
+                            style=format!("background: none; border: none; cursor: pointer; font-size: 0.8rem; color: {}", t.warning_text_dark)
+                            title=l.t(Msg::Dismiss)
+                            aria-label=l.t(Msg::Dismiss)
+                            on:click=move |_| dismissed.update(|d| d.push(item_key))
+                        >
+                            "\u{2715}"
+                        </button>
+                    </li>
+                })
+            }).collect_view();
+
+            Some(view! {
+                <section
+                    aria-label=l.t(Msg::UpcomingClockChanges)
+                    style=format!(
+                        "margin: 16px 32px 0; padding: 10px 16px; border-radius: 8px; font-size: 0.8rem; background: {}; border: 1px solid {}",
+                        t.warning_bg, t.warning_border
+                    )
+                >
+                    <ul style="display: flex; flex-direction: column; gap: 8px">{items}</ul>
+                </section>
+            })
+        }}
+    }
+}
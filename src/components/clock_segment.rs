@@ -1,98 +1,274 @@
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
 use crate::modules::*;
+use super::clock::HATCH_PATTERN_ID;
+
+/// More meetings than this in one slot collapse into a count badge.
+const MAX_STACKED: usize = 3;
 
 #[component]
 pub fn ClockSegment(
     hour: u32,
-    outer_r: f64,
-    inner_r: f64,
     ring_index: usize,
-    tz_offset: f64,
-    ref_offset: f64,
-    theme: ThemeColors,
+    geometry: Memo<Option<RingGeometry>>,
+    tz_offset: Memo<f64>,
+    work_hours: Memo<(f64, f64)>,
+    ref_offset: Memo<f64>,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
     meetings: ReadSignal<Vec<Meeting>>,
     set_selected: WriteSignal<Option<SelectedSlot>>,
-    active_zones: Vec<TimezoneEntry>,
+    active_zones: ReadSignal<ActiveTimezones>,
+    /// Drag state shared across the clock; `None` when meetings are read-only.
+    dragging: Option<RwSignal<Option<Vec<u32>>>>,
+    /// (ring, hour) of the one segment reachable with Tab; arrow keys move it.
+    focus: RwSignal<(usize, u32)>,
+    /// (ring pressed, ring under the pointer) while reordering; `None` when rings are fixed.
+    ring_drag: Option<RwSignal<Option<(usize, usize)>>>,
+    /// Stripe working-hours segments so they do not rely on color alone.
+    hatch: Signal<bool>,
 ) -> impl IntoView {
-    let display_hour = convert_between(hour as f64, ref_offset, tz_offset);
-    let is_working = is_work_hour(display_hour);
-    let fill = if is_working { "#22c55e" } else { theme.ring_defaults[ring_index] };
+    // Each piece of derived data is its own memo, so a change only touches
+    // the attributes that actually depend on it.
+    let geo = move || geometry.get().unwrap_or(RingGeometry { outer_r: 0.0, inner_r: 0.0 });
+    let display_hour = Memo::new(move |_| convert_between(hour as f64, ref_offset.get(), tz_offset.get()));
+    let is_working = Memo::new(move |_| {
+        let (start, end) = work_hours.get();
+        (start..end).contains(&display_hour.get())
+    });
+
+    // Colors follow the zone, not the ring position
+    let palette = Memo::new(move |_| active_zones.with(|z| z.palette_index(ring_index)));
 
-    let path = segment_path(CX, CY, outer_r, inner_r, hour as f64, (hour + 1) as f64);
-    let (lx, ly) = label_position(CX, CY, outer_r, inner_r, hour as f64);
+    // Compute UTC hour for meeting lookup
+    let utc_hour = Memo::new(move |_| ((hour as f64 - ref_offset.get() + 48.0) % 24.0).round() as u32 % 24);
 
-    // Format label: show HH:MM for fractional timezones, HH for integer
-    let frac = display_hour % 1.0;
-    let label_text = if frac.abs() < 0.01 || (1.0 - frac).abs() < 0.01 {
-        format!("{:02}", display_hour.floor() as u32 % 24)
-    } else {
-        let mins = (frac * 60.0).round() as u32;
-        format!("{:02}:{:02}", display_hour.floor() as u32 % 24, mins)
+    let path = move || {
+        let g = geo();
+        segment_path(CX, CY, g.outer_r, g.inner_r, hour as f64, (hour + 1) as f64)
     };
+    let label_pos = Memo::new(move |_| {
+        let g = geo();
+        label_position(CX, CY, g.outer_r, g.inner_r, hour as f64)
+    });
+
+    // Whole hours only, with minutes for fractional timezones. Thin rings
+    // label every other hour so the numbers do not collide.
+    let label_text = Memo::new(move |_| {
+        if !hour.is_multiple_of(label_step(&geo())) {
+            return String::new();
+        }
+        time_format.with(|f| f.compact(display_hour.get()))
+    });
 
     // Dynamic font size based on ring thickness
-    let font_size = ((outer_r - inner_r) / 5.0).clamp(5.0, 8.0);
+    let font_size = move || {
+        let g = geo();
+        ((g.outer_r - g.inner_r) / 5.0).clamp(5.0, 8.0)
+    };
 
-    // Compute UTC hour for meeting lookup
-    let utc_hour = ((hour as f64 - ref_offset + 48.0) % 24.0).round() as u32 % 24;
+    // Meetings in this hour whose organizer sits on this ring: (id, title, essential)
+    let slot_meetings = Memo::new(move |_| {
+        let utc_hour = utc_hour.get();
+        active_zones.with(|z| meetings.with(|ms| {
+            ms.iter()
+                .filter(|m| m.utc_hour == utc_hour && z.meeting_ring(m) == ring_index)
+                .map(|m| (m.id, m.title.clone(), m.essential))
+                .collect::<Vec<_>>()
+        }))
+    });
 
-    // Meeting dot only on outermost ring (ring_index == 0)
-    let meeting_dot = if ring_index == 0 {
-        let meetings_val = meetings.get_untracked();
-        meetings_val.iter().find(|m| m.utc_hour == utc_hour).map(|m| {
-            let dot_color = if m.essential { theme.meeting_essential } else { theme.meeting_non_essential };
-            let bg = theme.background;
-            (dot_color, bg)
-        })
-    } else {
-        None
+    let select = move || {
+        let utc_hour = utc_hour.get_untracked();
+        set_selected.set(Some(active_zones.with_untracked(|z| SelectedSlot::at(utc_hour, z))));
     };
+    let on_click = move |_| select();
 
-    let on_click = {
-        let active_zones = active_zones.clone();
-        move |_| {
-            let local_hours: Vec<f64> = active_zones.iter()
-                .map(|tz| utc_to_local(utc_hour as f64, tz.utc_offset))
-                .collect();
-            set_selected.set(Some(SelectedSlot {
-                utc_hour,
-                local_hours,
-            }));
+    // Screen readers hear every zone's time at this hour and whether this
+    // ring's zone is working, e.g.
+    // "10:00 Dallas (CST), 16:00 London (GMT), working hours in London (GMT)"
+    let aria_label = Memo::new(move |_| {
+        let l = lang.get();
+        let reference = ref_offset.get();
+        let mut parts: Vec<String> = active_zones.with(|z| z.zones().iter().map(|tz| {
+            let local = convert_between(hour as f64, reference, tz.utc_offset);
+            format!("{} {}", time_format.with(|f| f.time(local)), l.zone_name(tz))
+        }).collect());
+        let status = if is_working.get() { Msg::WorkingHoursIn } else { Msg::OutsideHoursIn };
+        if let Some(tz) = active_zones.with(|z| z.zones().get(ring_index).cloned()) {
+            parts.push(l.fill(status, &[l.zone_name(&tz)]));
+        }
+        let n = slot_meetings.with(Vec::len);
+        if n > 0 {
+            parts.push(l.count(Count::Meetings, n));
         }
+        parts.join(", ")
+    });
+
+    // Roving tabindex: only the focus segment is in the tab order. Rings
+    // beyond the current count fall back to the innermost one.
+    let tab_index = move || {
+        let (ring, h) = focus.get();
+        let ring = ring.min(active_zones.with(|z| z.zones().len()).saturating_sub(1));
+        if (ring, h) == (ring_index, hour) { "0" } else { "-1" }
     };
+    let (has_focus, set_has_focus) = signal(false);
+    let t = &THEME_VARS;
+    // Fill and focus stroke go through `style` so the CSS variables resolve
+    let segment_style = move || format!(
+        "outline: none; fill: {}; stroke: {}",
+        if is_working.get() { t.working_hours.to_string() } else { t.ring_fill(palette.get()) },
+        if has_focus.get() { t.text_primary } else { t.segment_stroke }
+    );
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let key = ev.key();
+        if matches!(key.as_str(), "Enter" | " ") {
+            ev.prevent_default();
+            select();
+            return;
+        }
+        let ring_count = active_zones.with_untracked(|z| z.zones().len());
+        let Some((ring, h)) = dial_neighbor((ring_index, hour), &key, ring_count) else { return };
+        ev.prevent_default();
+        focus.set((ring, h));
+        let target = ev.current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .and_then(|el| el.closest("svg").ok().flatten())
+            .and_then(|svg| svg.query_selector(&format!("[data-ring=\"{}\"][data-hour=\"{}\"]", ring, h)).ok().flatten())
+            .and_then(|el| el.dyn_into::<web_sys::SvgElement>().ok());
+        if let Some(el) = target {
+            let _ = el.focus();
+        }
+    };
+
+    // Pressing a marker starts dragging the meetings it represents
+    let start_drag = move |ids: Vec<u32>| {
+        move |ev: leptos::ev::PointerEvent| {
+            if let Some(dragging) = dragging {
+                ev.prevent_default();
+                dragging.set(Some(ids.clone()));
+            }
+        }
+    };
+    let marker_cursor = if dragging.is_some() { "grab" } else { "pointer" };
 
     view! {
-        <g>
+        <g data-utc-hour=move || utc_hour.get()>
             <path
                 d=path
-                fill=fill
-                stroke=theme.segment_stroke
-                stroke-width="1"
+                stroke-width=move || if has_focus.get() { "2.5" } else { "1" }
                 cursor="pointer"
+                role="button"
+                aria-label=move || aria_label.get()
+                tabindex=tab_index
+                data-ring=ring_index
+                data-hour=hour
+                style=segment_style
                 on:click=on_click
+                on:pointerdown=move |ev| {
+                    if let Some(ring_drag) = ring_drag.filter(|_| ev.button() == 0) {
+                        ring_drag.set(Some((ring_index, ring_index)));
+                    }
+                }
+                on:pointerenter=move |_| {
+                    if let Some(ring_drag) = ring_drag.filter(|d| d.with_untracked(Option::is_some)) {
+                        ring_drag.update(|d| if let Some((_, over)) = d { *over = ring_index });
+                    }
+                }
+                on:keydown=on_keydown
+                on:focus=move |_| {
+                    focus.set((ring_index, hour));
+                    set_has_focus.set(true);
+                }
+                on:blur=move |_| set_has_focus.set(false)
             />
+            {move || (hatch.get() && is_working.get()).then(|| view! {
+                <path d=path fill=format!("url(#{})", HATCH_PATTERN_ID) style="pointer-events: none" aria-hidden="true" />
+            })}
             <text
-                x=lx
-                y=ly
+                x=move || label_pos.get().0
+                y=move || label_pos.get().1
                 text-anchor="middle"
                 dominant-baseline="middle"
                 font-size=font_size
                 font-weight="500"
-                fill=theme.text_primary
-                style="pointer-events: none"
+                style=format!("pointer-events: none; fill: {}", t.text_primary)
+                aria-hidden="true"
             >
-                {label_text}
+                {move || label_text.get()}
             </text>
-            {meeting_dot.map(|(dot_color, bg)| view! {
-                <circle
-                    cx={lx + 10.0}
-                    cy=ly
-                    r="4"
-                    fill=dot_color
-                    stroke=bg
-                    stroke-width="1"
-                />
-            })}
+            {move || {
+                let slot = slot_meetings.get();
+                if slot.is_empty() {
+                    return None;
+                }
+                let l = lang.get();
+                let tooltip = slot.iter().map(|(_, title, essential)| {
+                    if *essential {
+                        format!("\u{2022} {} {}", title, l.t(Msg::EssentialTag))
+                    } else {
+                        format!("\u{2022} {}", title)
+                    }
+                }).collect::<Vec<_>>().join("\n");
+                let tooltip = format!("{}\n{}", l.fill(Msg::MeetingsAtHour, &[&l.count(Count::Meetings, slot.len())]), tooltip);
+
+                // Up to MAX_STACKED dots side by side; beyond that a single badge with the count
+                let markers = if slot.len() <= MAX_STACKED {
+                    slot.iter().enumerate().map(|(i, (id, _, essential))| {
+                        let essential = *essential;
+                        view! {
+                            <circle
+                                on:pointerdown=start_drag(vec![*id])
+                                cx=move || label_pos.get().0 + 9.0 + i as f64 * 6.0
+                                cy=move || label_pos.get().1
+                                r=if slot.len() == 1 { "4" } else { "3" }
+                                style=format!(
+                                    "fill: {}; stroke: {}",
+                                    if essential { t.meeting_essential } else { t.meeting_non_essential },
+                                    t.background
+                                )
+                                stroke-width="1"
+                            />
+                        }
+                    }).collect_view().into_any()
+                } else {
+                    let any_essential = slot.iter().any(|(_, _, essential)| *essential);
+                    let count = slot.len().to_string();
+                    let ids: Vec<u32> = slot.iter().map(|(id, _, _)| *id).collect();
+                    view! {
+                        <circle
+                            on:pointerdown=start_drag(ids)
+                            cx=move || label_pos.get().0 + 11.0
+                            cy=move || label_pos.get().1
+                            r="5.5"
+                            style=format!(
+                                "fill: {}; stroke: {}",
+                                if any_essential { t.meeting_essential } else { t.meeting_non_essential },
+                                t.background
+                            )
+                            stroke-width="1"
+                        />
+                        <text
+                            x=move || label_pos.get().0 + 11.0
+                            y=move || label_pos.get().1
+                            text-anchor="middle"
+                            dominant-baseline="central"
+                            font-size="6"
+                            font-weight="700"
+                            style=format!("pointer-events: none; fill: {}", t.background)
+                        >
+                            {count}
+                        </text>
+                    }.into_any()
+                };
+
+                Some(view! {
+                    <g cursor=marker_cursor style="touch-action: none" aria-hidden="true" on:click=on_click>
+                        <title>{tooltip}</title>
+                        {markers}
+                    </g>
+                })
+            }}
         </g>
     }
 }
@@ -0,0 +1,141 @@
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::modules::*;
+
+pub type PendingImport = Option<Result<ConfigDocument, Vec<ImportError>>>;
+
+/// Export/import buttons for the full configuration, plus the review step
+/// shown after a file is picked (validation errors, or merge/replace).
+#[component]
+pub fn ConfigTransfer(
+    workspaces: ReadSignal<Vec<Workspace>>,
+    mode: ReadSignal<ModeSetting>,
+    on_import: Callback<(ConfigDocument, ImportMode)>,
+    pending: ReadSignal<PendingImport>,
+    set_pending: WriteSignal<PendingImport>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    let on_export = move |_| {
+        let json = export_config(&workspaces.get_untracked(), mode.get_untracked());
+        download_json("tz-clock-config.json", &json);
+    };
+
+    let on_file = move |ev: leptos::ev::Event| {
+        read_picked_file(&ev, move |text| set_pending.set(Some(parse_config(&text))));
+    };
+
+    view! {
+        {move || {
+            let t = THEME_VARS;
+            let l = lang.get();
+            let button_style = format!(
+                "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 4px 10px; font-size: 11px; cursor: pointer",
+                t.button_secondary_bg, t.text_secondary, t.card_border
+            );
+
+            let review = pending.get().map(|result| match result {
+                Err(errors) => view! {
+                    <div style=format!(
+                        "padding: 8px 12px; border-radius: 6px; font-size: 11px; max-width: 600px; background: {}; border: 1px solid {}; color: {}",
+                        t.warning_bg, t.warning_border, t.warning_text_dark
+                    )>
+                        <strong>{l.fill(Msg::ImportFailed, &[&l.count(Count::Problems, errors.len())])}</strong>
+                        <ul style="margin: 4px 0 8px 16px">
+                            {errors.iter().map(|e| view! { <li>{l.import_error(e)}</li> }).collect_view()}
+                        </ul>
+                        <button style=button_style.clone() on:click=move |_| set_pending.set(None)>{l.t(Msg::Dismiss)}</button>
+                    </div>
+                }.into_any(),
+                Ok(doc) => {
+                    let zone_count: usize = doc.workspaces.iter().map(|w| w.zones.zones().len()).sum();
+                    let meeting_count: usize = doc.workspaces.iter().map(|w| w.meetings.len()).sum();
+                    let summary = l.fill(Msg::ImportSummary, &[
+                        &l.count(Count::Workspaces, doc.workspaces.len()),
+                        &l.count(Count::Zones, zone_count),
+                        &l.count(Count::Meetings, meeting_count),
+                    ]);
+                    let merge_doc = doc.clone();
+                    let primary_style = format!(
+                        "background: {}; color: {}; border: none; border-radius: 6px; padding: 4px 10px; font-size: 11px; cursor: pointer",
+                        t.button_primary_bg, t.button_primary_text
+                    );
+                    view! {
+                        <div style=format!(
+                            "display: flex; align-items: center; gap: 8px; flex-wrap: wrap; padding: 8px 12px; border-radius: 6px; font-size: 11px; background: {}; border: 1px solid {}; color: {}",
+                            t.success_bg, t.success_border, t.success_text_dark
+                        )>
+                            <span>{summary}</span>
+                            <button
+                                style=primary_style.clone()
+                                title=l.t(Msg::MergeHint)
+                                on:click=move |_| {
+                                    on_import.run((merge_doc.clone(), ImportMode::Merge));
+                                    set_pending.set(None);
+                                }
+                            >
+                                {l.t(Msg::Merge)}
+                            </button>
+                            <button
+                                style=primary_style
+                                title=l.t(Msg::ReplaceHint)
+                                on:click=move |_| {
+                                    on_import.run((doc.clone(), ImportMode::Replace));
+                                    set_pending.set(None);
+                                }
+                            >
+                                {l.t(Msg::Replace)}
+                            </button>
+                            <button style=button_style.clone() on:click=move |_| set_pending.set(None)>{l.t(Msg::Cancel)}</button>
+                        </div>
+                    }.into_any()
+                }
+            });
+
+            view! {
+                <div style="display: flex; flex-direction: column; align-items: center; gap: 8px">
+                    <div style="display: flex; gap: 8px; align-items: center">
+                        <button style=button_style.clone() on:click=on_export>{l.t(Msg::ExportJson)}</button>
+                        <label style=button_style.clone()>
+                            {l.t(Msg::ImportJson)}
+                            <input type="file" accept="application/json,.json" style="display: none" on:change=on_file />
+                        </label>
+                    </div>
+                    {review}
+                </div>
+            }
+        }}
+    }
+}
+
+/// Read the file chosen in the `<input type="file">` that fired `ev` as text
+pub(crate) fn read_picked_file(ev: &leptos::ev::Event, on_text: impl FnOnce(String) + 'static) {
+    let Some(input) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok()) else { return };
+    let Some(file) = input.files().and_then(|files| files.get(0)) else { return };
+    // Reset so picking the same file again still fires `change`
+    input.set_value("");
+    let Ok(reader) = web_sys::FileReader::new() else { return };
+    let reader_for_load = reader.clone();
+    let on_load = Closure::once_into_js(move || {
+        let text = reader_for_load.result().ok().and_then(|r| r.as_string()).unwrap_or_default();
+        on_text(text);
+    });
+    reader.set_onload(Some(on_load.unchecked_ref()));
+    let _ = reader.read_as_text(&file);
+}
+
+/// Offer `contents` as a file download
+pub(crate) fn download_json(filename: &str, contents: &str) {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else { return };
+    let parts = js_sys::Array::of1(&JsValue::from_str(contents));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type("application/json");
+    let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&parts, &options) else { return };
+    let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) else { return };
+    if let Ok(anchor) = document.create_element("a").map(JsCast::unchecked_into::<web_sys::HtmlAnchorElement>) {
+        anchor.set_href(&url);
+        anchor.set_download(filename);
+        anchor.click();
+    }
+    let _ = web_sys::Url::revoke_object_url(&url);
+}
@@ -1,19 +1,35 @@
 use leptos::prelude::*;
+use wasm_bindgen::JsCast;
 use crate::modules::*;
+use super::workspaces::WorkspaceSwitcher;
+use super::config_transfer::{ConfigTransfer, PendingImport};
+use super::theme_editor::ThemeEditor;
 
 #[component]
 pub fn Header(
     active_zones: ReadSignal<ActiveTimezones>,
-    theme: Signal<&'static ThemeColors>,
+    workspaces: ReadSignal<Vec<Workspace>>,
+    set_workspaces: WriteSignal<Vec<Workspace>>,
+    active_workspace: ReadSignal<u32>,
+    set_active_workspace: WriteSignal<u32>,
+    can_undo: Signal<bool>,
+    can_redo: Signal<bool>,
+    on_undo: Callback<()>,
+    on_redo: Callback<()>,
+    layout: ReadSignal<ClockLayout>,
+    set_layout: WriteSignal<ClockLayout>,
+    lang: Signal<Language>,
 ) -> impl IntoView {
     let subtitle = move || {
         let z = active_zones.get();
-        z.zones.iter().map(|tz| tz.name.to_string()).collect::<Vec<_>>().join(" \u{2022} ")
+        let l = lang.get();
+        z.zones().iter().map(|tz| l.zone_name(tz).to_string()).collect::<Vec<_>>().join(" \u{2022} ")
     };
 
     view! {
         {move || {
-            let t = *theme.get();
+            let t = THEME_VARS;
+            let l = lang.get();
 
             view! {
                 <div
@@ -25,12 +41,63 @@ pub fn Header(
                 >
                     <div>
                         <h1 style=format!("font-size: 1.5rem; font-weight: 300; margin-bottom: 4px; color: {}", t.text_primary)>
-                            "Timezone Meeting Clock"
+                            {l.t(Msg::Title)}
                         </h1>
                         <p style=format!("font-size: 0.75rem; color: {}", t.text_secondary)>
                             {subtitle}
                         </p>
                     </div>
+                    <div style="display: flex; gap: 12px; align-items: center; flex-wrap: wrap">
+                        <WorkspaceSwitcher
+                            workspaces=workspaces
+                            set_workspaces=set_workspaces
+                            active_workspace=active_workspace
+                            set_active_workspace=set_active_workspace
+                            lang=lang
+                        />
+                        // Dial / timeline / week
+                        <div style="display: flex; gap: 4px">
+                            {[(ClockLayout::Dial, Msg::LayoutDial), (ClockLayout::Timeline, Msg::LayoutTimeline), (ClockLayout::Week, Msg::LayoutWeek)]
+                                .into_iter()
+                                .map(|(option, msg)| {
+                                    let is_active = move || layout.get() == option;
+                                    view! {
+                                        <button
+                                            aria-pressed=move || is_active().to_string()
+                                            style=move || format!(
+                                                "background: {}; color: {}; border: none; border-radius: 6px; padding: 4px 8px; font-size: 11px; cursor: pointer",
+                                                if is_active() { t.button_primary_bg } else { t.button_secondary_bg },
+                                                if is_active() { t.button_primary_text } else { t.text_secondary }
+                                            )
+                                            on:click=move |_| set_layout.set(option)
+                                        >
+                                            {l.t(msg)}
+                                        </button>
+                                    }
+                                })
+                                .collect_view()}
+                        </div>
+                        <div style="display: flex; gap: 4px">
+                            {[("\u{21B6}", l.t(Msg::UndoShortcut), can_undo, on_undo), ("\u{21B7}", l.t(Msg::RedoShortcut), can_redo, on_redo)]
+                                .into_iter()
+                                .map(|(icon, label, enabled, action)| view! {
+                                    <button
+                                        title=label
+                                        aria-label=label
+                                        disabled=move || !enabled.get()
+                                        style=move || format!(
+                                            "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 2px 10px; font-size: 14px; cursor: pointer; opacity: {}",
+                                            t.button_secondary_bg, t.text_secondary, t.card_border,
+                                            if enabled.get() { 1.0 } else { 0.4 }
+                                        )
+                                        on:click=move |_| action.run(())
+                                    >
+                                        {icon}
+                                    </button>
+                                })
+                                .collect_view()}
+                        </div>
+                    </div>
                 </div>
             }
         }}
@@ -43,24 +110,79 @@ pub fn TimezoneConfigurator(
     set_active_zones: WriteSignal<ActiveTimezones>,
     theme_name: ReadSignal<ThemeName>,
     set_theme_name: WriteSignal<ThemeName>,
-    mode: ReadSignal<Mode>,
-    set_mode: WriteSignal<Mode>,
-    theme: Signal<&'static ThemeColors>,
+    custom_themes: ReadSignal<Vec<CustomTheme>>,
+    set_custom_themes: WriteSignal<Vec<CustomTheme>>,
+    /// Custom theme open in the editor, previewed app-wide
+    theme_draft: ReadSignal<Option<ThemeDraft>>,
+    set_theme_draft: WriteSignal<Option<ThemeDraft>>,
+    /// The light/dark mode currently shown
+    mode: Signal<Mode>,
+    mode_setting: ReadSignal<ModeSetting>,
+    set_mode_setting: WriteSignal<ModeSetting>,
+    /// Today's sunrise and sunset in the reference zone, for the Sunset setting's hint
+    daylight: Memo<Daylight>,
+    reference: Signal<Reference>,
+    reference_zone: ReadSignal<ReferenceZone>,
+    set_reference_zone: WriteSignal<ReferenceZone>,
+    /// Zone id of the viewer's own zone, labelled on its ring
+    local_zone: Option<&'static str>,
+    hour_cycle: ReadSignal<HourCycle>,
+    set_hour_cycle: WriteSignal<HourCycle>,
+    /// Stripes over working hours on the dial
+    hatch: ReadSignal<bool>,
+    set_hatch: WriteSignal<bool>,
+    time_format: Signal<TimeFormat>,
+    language: ReadSignal<Language>,
+    set_language: WriteSignal<Language>,
+    workspaces: ReadSignal<Vec<Workspace>>,
+    on_import: Callback<(ConfigDocument, ImportMode)>,
 ) -> impl IntoView {
     let (expanded, set_expanded) = signal(false);
+    // Ring whose handle is being dragged, and the ring it is over
+    let drag_from = RwSignal::new(None::<usize>);
+    let drag_over = RwSignal::new(None::<usize>);
+    let move_ring = move |from: usize, to: usize| {
+        let mut current = active_zones.get_untracked();
+        current.move_zone(from, to);
+        set_active_zones.set(current);
+    };
+    // Lives outside the view closure so a pending import survives re-renders
+    let (pending_import, set_pending_import) = signal(PendingImport::None);
+
+    // Opens the current theme in the editor: a custom theme is edited in
+    // place, a built-in one is copied into a new custom theme
+    let toggle_editor = move |_| {
+        if theme_draft.with_untracked(Option::is_some) {
+            set_theme_draft.set(None);
+            return;
+        }
+        let themes = custom_themes.get_untracked();
+        let current = theme_name.get_untracked();
+        let existing = match current {
+            ThemeName::Custom(id) => themes.iter().find(|t| t.id == id).cloned(),
+            _ => None,
+        };
+        let name = language.get_untracked().t(Msg::NewThemeName);
+        let theme = existing.unwrap_or_else(|| CustomTheme::from_base(next_theme_id(&themes), name, current, &themes));
+        set_theme_draft.set(Some(theme.into()));
+    };
 
     view! {
         {move || {
-            let t = *theme.get();
+            let t = THEME_VARS;
             let zones = active_zones.get();
-            let n = zones.zones.len();
+            let n = zones.zones().len();
             let can_add = n < MAX_RINGS;
             let can_remove = n > 1;
             let is_expanded = expanded.get();
             let current_theme = theme_name.get();
-            let current_mode = mode.get();
+            let current_setting = mode_setting.get();
+            let current_reference = reference_zone.get();
+            let current_cycle = hour_cycle.get();
+            let tf = time_format.get();
+            let l = language.get();
 
-            let toggle_label = if is_expanded { "\u{25B2} Config" } else { "\u{25BC} Config" };
+            let toggle_label = format!("{} {}", if is_expanded { "\u{25B2}" } else { "\u{25BC}" }, l.t(Msg::Config));
 
             let select_style = format!(
                 "border: 1px solid {}; background: {}; color: {}; border-radius: 6px; padding: 4px 6px; cursor: pointer; font-size: 12px",
@@ -88,7 +210,9 @@ pub fn TimezoneConfigurator(
                                 // Theme + mode row
                                 <div style="display: flex; justify-content: center; gap: 16px; align-items: center; flex-wrap: wrap">
                                     <div style="display: flex; gap: 4px; flex-wrap: wrap">
-                                        {ThemeName::ALL.into_iter().map(|tn| {
+                                        {ThemeName::ALL.into_iter().map(|tn| (tn, tn.label().to_string()))
+                                            .chain(custom_themes.get().into_iter().map(|c| (ThemeName::Custom(c.id), c.name)))
+                                            .map(|(tn, label)| {
                                             let is_active = current_theme == tn;
                                             let bg = if is_active { t.button_primary_bg } else { t.button_secondary_bg };
                                             let color = if is_active { t.button_primary_text } else { t.text_secondary };
@@ -100,55 +224,239 @@ pub fn TimezoneConfigurator(
                                                     )
                                                     on:click=move |_| set_theme_name.set(tn)
                                                 >
-                                                    {tn.label()}
+                                                    {label}
                                                 </button>
                                             }
                                         }).collect_view()}
+                                        <button
+                                            style=format!(
+                                                "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 4px 8px; font-size: 10px; cursor: pointer",
+                                                t.button_secondary_bg, t.text_secondary, t.card_border
+                                            )
+                                            title=l.t(Msg::CustomizeTheme)
+                                            aria-label=l.t(Msg::CustomizeTheme)
+                                            aria-expanded=move || theme_draft.with(Option::is_some).to_string()
+                                            on:click=toggle_editor
+                                        >
+                                            "\u{270E}"
+                                        </button>
                                     </div>
 
-                                    // Light/dark toggle
-                                    <button
-                                        style=format!(
-                                            "background: {}; border: 1px solid {}; border-radius: 20px; padding: 3px; cursor: pointer; width: 50px; position: relative; transition: all 0.3s ease",
-                                            t.card_bg, t.card_border
-                                        )
-                                        on:click=move |_| {
-                                            set_mode.set(if current_mode == Mode::Light { Mode::Dark } else { Mode::Light });
-                                        }
-                                    >
-                                        <div style=format!(
-                                            "width: 20px; height: 20px; border-radius: 50%; background: {}; transform: translateX({}px); transition: transform 0.3s ease",
-                                            t.button_primary_bg,
-                                            if current_mode == Mode::Dark { 24 } else { 0 }
-                                        ) />
-                                    </button>
+                                    // Light / dark / system / sunset
+                                    <div style="display: flex; gap: 4px">
+                                        {ModeSetting::ALL.into_iter().map(|setting| {
+                                            let is_active = current_setting == setting;
+                                            let (label, hint) = match setting {
+                                                ModeSetting::Light => (l.t(Msg::ModeLight), None),
+                                                ModeSetting::Dark => (l.t(Msg::ModeDark), None),
+                                                ModeSetting::System => (l.t(Msg::ModeSystem), Some(l.t(Msg::ModeSystemHint).to_string())),
+                                                ModeSetting::Sunset => (l.t(Msg::ModeSunset), Some(sunset_hint(l, daylight.get(), reference.get(), &tf))),
+                                            };
+                                            view! {
+                                                <button
+                                                    style=format!(
+                                                        "background: {}; color: {}; border: none; border-radius: 6px; padding: 4px 8px; font-size: 10px; cursor: pointer",
+                                                        if is_active { t.button_primary_bg } else { t.button_secondary_bg },
+                                                        if is_active { t.button_primary_text } else { t.text_secondary }
+                                                    )
+                                                    title=hint
+                                                    aria-pressed=is_active.to_string()
+                                                    on:click=move |_| set_mode_setting.set(setting)
+                                                >
+                                                    {label}
+                                                </button>
+                                            }
+                                        }).collect_view()}
+                                    </div>
+
+                                    // 24h / 12h clock
+                                    <div style="display: flex; gap: 4px">
+                                        {[HourCycle::H24, HourCycle::H12].into_iter().map(|cycle| {
+                                            let is_active = current_cycle == cycle;
+                                            view! {
+                                                <button
+                                                    style=format!(
+                                                        "background: {}; color: {}; border: none; border-radius: 6px; padding: 4px 8px; font-size: 10px; cursor: pointer",
+                                                        if is_active { t.button_primary_bg } else { t.button_secondary_bg },
+                                                        if is_active { t.button_primary_text } else { t.text_secondary }
+                                                    )
+                                                    on:click=move |_| set_hour_cycle.set(cycle)
+                                                >
+                                                    {cycle.label()}
+                                                </button>
+                                            }
+                                        }).collect_view()}
+                                    </div>
+
+                                    // Working-hours stripes
+                                    <label style=format!("font-size: 11px; display: flex; align-items: center; gap: 6px; color: {}", t.text_secondary)>
+                                        <input
+                                            type="checkbox"
+                                            prop:checked=hatch.get()
+                                            on:change=move |ev| set_hatch.set(event_target_checked(&ev))
+                                        />
+                                        {l.t(Msg::HatchWorkingHours)}
+                                    </label>
+
+                                    // UI language
+                                    <label style=format!("font-size: 11px; display: flex; align-items: center; gap: 6px; color: {}", t.text_secondary)>
+                                        {l.t(Msg::Language)}
+                                        <select
+                                            style=select_style.clone()
+                                            prop:value=l.code()
+                                            on:change=move |ev| {
+                                                if let Some(lang) = Language::from_code(&event_target_value(&ev)) {
+                                                    set_language.set(lang);
+                                                }
+                                            }
+                                        >
+                                            {Language::ALL.into_iter().map(|lang| view! {
+                                                <option value=lang.code() selected=lang == l>{lang.native_name()}</option>
+                                            }).collect_view()}
+                                        </select>
+                                    </label>
+
+                                    // Reference zone the dial is anchored to
+                                    <label style=format!("font-size: 11px; display: flex; align-items: center; gap: 6px; color: {}", t.text_secondary)>
+                                        {l.t(Msg::AnchorDialTo)}
+                                        <select
+                                            style=select_style.clone()
+                                            prop:value=current_reference.key()
+                                            on:change=move |ev| {
+                                                if let Some(r) = ReferenceZone::from_key(&event_target_value(&ev)) {
+                                                    set_reference_zone.set(r);
+                                                }
+                                            }
+                                        >
+                                            {std::iter::once(ReferenceZone::FirstRing)
+                                                .chain(zones.zones().iter().map(|tz| ReferenceZone::Zone(tz.id)))
+                                                .chain([ReferenceZone::Utc, ReferenceZone::Local])
+                                                .map(|r| {
+                                                    let label = match r {
+                                                        ReferenceZone::FirstRing => l.t(Msg::OuterRing),
+                                                        ReferenceZone::Zone(id) => zones.zones().iter().find(|tz| tz.id == id).map(|tz| l.zone_name(tz)).unwrap_or_default(),
+                                                        ReferenceZone::Utc => l.t(Msg::Utc),
+                                                        ReferenceZone::Local => l.t(Msg::BrowserLocal),
+                                                    };
+                                                    view! {
+                                                        <option value=r.key() selected=r == current_reference>{label}</option>
+                                                    }
+                                                })
+                                                .collect_view()}
+                                        </select>
+                                    </label>
                                 </div>
 
+                                <ThemeEditor
+                                    draft=theme_draft
+                                    set_draft=set_theme_draft
+                                    custom_themes=custom_themes
+                                    set_custom_themes=set_custom_themes
+                                    theme_name=theme_name
+                                    set_theme_name=set_theme_name
+                                    mode=mode
+                                    lang=Signal::from(language)
+                                />
+
                                 // Timezone selectors row
                                 <div style="display: flex; justify-content: center; gap: 12px; align-items: center; overflow-x: auto; flex-wrap: wrap">
-                                    {zones.zones.iter().enumerate().map(|(i, tz)| {
+                                    {zones.zones().iter().enumerate().map(|(i, tz)| {
                                         let current_id = tz.id;
+                                        let (work_start, work_end) = (tz.work_start, tz.work_end);
                                         let sstyle = select_style.clone();
-                                        let ring_label = format!("Ring {}", i + 1);
-                                        let label_style = format!("font-size: 11px; display: block; margin-bottom: 4px; white-space: nowrap; color: {}", t.text_secondary);
+                                        let sstyle_hours = format!("{}; padding: 2px 4px; font-size: 11px", select_style);
+                                        let ring = l.fill(Msg::RingN, &[&(i + 1).to_string()]);
+                                        let ring_label = if Some(current_id) == local_zone {
+                                            format!("{} \u{00B7} {}", ring, l.t(Msg::YouAreHere))
+                                        } else {
+                                            ring
+                                        };
+                                        let label_style = format!("font-size: 11px; display: flex; align-items: center; gap: 4px; margin-bottom: 4px; white-space: nowrap; color: {}", t.text_secondary);
+                                        let swatch = t.ring_fill(zones.palette_index(i));
 
                                         view! {
-                                            <div style="flex-shrink: 0; display: flex; align-items: flex-end; gap: 4px">
+                                            <div
+                                                style=move || format!(
+                                                    "flex-shrink: 0; display: flex; align-items: flex-end; gap: 4px; border-radius: 6px; outline: {}; outline-offset: 2px",
+                                                    if drag_over.get() == Some(i) && drag_from.get() != Some(i) { format!("2px dashed {}", t.text_primary) } else { "none".to_string() }
+                                                )
+                                                on:dragover=move |ev| {
+                                                    if drag_from.get_untracked().is_some() {
+                                                        ev.prevent_default();
+                                                        drag_over.set(Some(i));
+                                                    }
+                                                }
+                                                on:drop=move |ev| {
+                                                    ev.prevent_default();
+                                                    if let Some(from) = drag_from.get_untracked() {
+                                                        move_ring(from, i);
+                                                    }
+                                                    drag_from.set(None);
+                                                    drag_over.set(None);
+                                                }
+                                            >
+                                                // Drag handle; arrow keys move the ring for keyboard users
+                                                <button
+                                                    draggable="true"
+                                                    data-ring-handle=i
+                                                    title=l.t(Msg::ReorderRing)
+                                                    aria-label=format!("{}: {}", l.fill(Msg::RingN, &[&(i + 1).to_string()]), l.t(Msg::ReorderRing))
+                                                    style=format!(
+                                                        "align-self: center; background: none; border: none; cursor: grab; padding: 4px 2px; font-size: 14px; color: {}",
+                                                        t.text_muted
+                                                    )
+                                                    on:dragstart=move |ev| {
+                                                        // Firefox only starts a drag that carries data
+                                                        if let Some(data) = ev.data_transfer() {
+                                                            let _ = data.set_data("text/plain", &i.to_string());
+                                                            data.set_effect_allowed("move");
+                                                        }
+                                                        drag_from.set(Some(i));
+                                                    }
+                                                    on:dragend=move |_| {
+                                                        drag_from.set(None);
+                                                        drag_over.set(None);
+                                                    }
+                                                    on:keydown=move |ev| {
+                                                        let to = match ev.key().as_str() {
+                                                            "ArrowLeft" | "ArrowUp" => i.checked_sub(1),
+                                                            "ArrowRight" | "ArrowDown" => Some(i + 1).filter(|&to| to < n),
+                                                            _ => return,
+                                                        };
+                                                        ev.prevent_default();
+                                                        let Some(to) = to else { return };
+                                                        move_ring(i, to);
+                                                        // The list re-renders, so follow the ring to its new handle
+                                                        request_animation_frame(move || {
+                                                            let handle = document()
+                                                                .query_selector(&format!("[data-ring-handle=\"{}\"]", to)).ok().flatten()
+                                                                .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+                                                            if let Some(handle) = handle {
+                                                                let _ = handle.focus();
+                                                            }
+                                                        });
+                                                    }
+                                                >
+                                                    "\u{2807}"
+                                                </button>
                                                 <div>
-                                                    <label style=label_style>{ring_label}</label>
+                                                    <label style=label_style>
+                                                        <span aria-hidden="true" style=format!("width: 10px; height: 10px; border-radius: 2px; border: 1px solid {}; background: {}", t.card_border, swatch) />
+                                                        {ring_label}
+                                                    </label>
                                                     <select
                                                         style=sstyle
                                                         prop:value=current_id
                                                         on:change=move |ev| {
                                                             let val = event_target_value(&ev);
                                                             let mut current = active_zones.get();
-                                                            if let Some(new_tz) = TIMEZONE_DATABASE.iter().find(|t| t.id == val) {
-                                                                if let Some(existing_idx) = current.zones.iter().position(|z| z.id == new_tz.id) {
+                                                            if let Some(new_tz) = zone_by_id(&val) {
+                                                                if let Some(existing_idx) = current.zones().iter().position(|z| z.id == new_tz.id) {
                                                                     if existing_idx != i {
-                                                                        current.zones.swap(i, existing_idx);
+                                                                        current.swap(i, existing_idx);
                                                                     }
                                                                 } else {
-                                                                    current.zones[i] = new_tz.clone();
+                                                                    current.replace(i, new_tz.clone());
                                                                 }
                                                                 set_active_zones.set(current);
                                                             }
@@ -156,7 +464,7 @@ pub fn TimezoneConfigurator(
                                                     >
                                                         {TIMEZONE_DATABASE.iter().map(|tz_opt| {
                                                             let tz_id = tz_opt.id;
-                                                            let display = format!("{} ({})", tz_opt.name, format_offset(tz_opt.utc_offset));
+                                                            let display = format!("{} ({})", l.zone_name(tz_opt), format_offset(tz_opt.utc_offset));
                                                             view! {
                                                                 <option value=tz_id selected=move || current_id == tz_id>
                                                                     {display}
@@ -164,6 +472,22 @@ pub fn TimezoneConfigurator(
                                                             }
                                                         }).collect_view()}
                                                     </select>
+                                                    <div style=format!("display: flex; align-items: center; gap: 4px; margin-top: 4px; font-size: 11px; color: {}", t.text_secondary)>
+                                                        {l.t(Msg::Work)}
+                                                        {work_hour_select(sstyle_hours.clone(), &tf, work_start, 0..24, move |h| {
+                                                            let mut current = active_zones.get();
+                                                            let end = current.zones()[i].work_end.max(h + 1.0);
+                                                            current.set_work_hours(i, h, end);
+                                                            set_active_zones.set(current);
+                                                        })}
+                                                        "\u{2013}"
+                                                        {work_hour_select(sstyle_hours.clone(), &tf, work_end, 1..25, move |h| {
+                                                            let mut current = active_zones.get();
+                                                            let start = current.zones()[i].work_start.min(h - 1.0);
+                                                            current.set_work_hours(i, start, h);
+                                                            set_active_zones.set(current);
+                                                        })}
+                                                    </div>
                                                 </div>
                                                 {if can_remove {
                                                     Some(view! {
@@ -174,7 +498,7 @@ pub fn TimezoneConfigurator(
                                                             )
                                                             on:click=move |_| {
                                                                 let mut current = active_zones.get();
-                                                                current.zones.remove(i);
+                                                                current.remove(i);
                                                                 set_active_zones.set(current);
                                                             }
                                                         >
@@ -198,20 +522,30 @@ pub fn TimezoneConfigurator(
                                                 style=add_style
                                                 on:click=move |_| {
                                                     let mut current = active_zones.get();
-                                                    let used_ids: Vec<&str> = current.zones.iter().map(|z| z.id).collect();
+                                                    let used_ids: Vec<&str> = current.zones().iter().map(|z| z.id).collect();
                                                     if let Some(new_tz) = TIMEZONE_DATABASE.iter().find(|t| !used_ids.contains(&t.id)) {
-                                                        current.zones.push(new_tz.clone());
+                                                        current.push(new_tz.clone());
                                                         set_active_zones.set(current);
                                                     }
                                                 }
                                             >
-                                                "+ Add"
+                                                {l.t(Msg::AddZone)}
                                             </button>
                                         })
                                     } else {
                                         None
                                     }}
                                 </div>
+
+                                // Backup / transfer row
+                                <ConfigTransfer
+                                    workspaces=workspaces
+                                    mode=mode_setting
+                                    on_import=on_import
+                                    pending=pending_import
+                                    set_pending=set_pending_import
+                                    lang=Signal::from(language)
+                                />
                             </div>
                         })
                     } else {
@@ -223,6 +557,49 @@ pub fn TimezoneConfigurator(
     }
 }
 
+/// Whole-hour dropdown for one end of a zone's working hours; hour 24 is
+/// the end of the day
+fn work_hour_select(
+    style: String,
+    time_format: &TimeFormat,
+    current: f64,
+    hours: std::ops::Range<u32>,
+    on_pick: impl Fn(f64) + 'static,
+) -> impl IntoView {
+    view! {
+        <select
+            style=style
+            prop:value=(current.floor() as u32).to_string()
+            on:change=move |ev| {
+                if let Ok(h) = event_target_value(&ev).parse::<f64>() {
+                    on_pick(h);
+                }
+            }
+        >
+            {hours.map(|h| {
+                view! {
+                    <option value=h.to_string() selected=h as f64 == current.floor()>
+                        {time_format.end_time(h as f64)}
+                    </option>
+                }
+            }).collect_view()}
+        </select>
+    }
+}
+
+/// Tooltip for the Sunset setting: today's switch times in the reference zone
+fn sunset_hint(l: Language, daylight: Daylight, reference: Reference, time_format: &TimeFormat) -> String {
+    match daylight {
+        Daylight::Times { sunrise, sunset } => l.fill(Msg::SunsetToday, &[
+            &time_format.time(sunset + reference.utc_offset),
+            &time_format.time(sunrise + reference.utc_offset),
+            reference.short_name,
+        ]),
+        Daylight::PolarDay => l.fill(Msg::SunNeverSets, &[reference.short_name]),
+        Daylight::PolarNight => l.fill(Msg::SunNeverRises, &[reference.short_name]),
+    }
+}
+
 fn format_offset(offset: f64) -> String {
     let abs = offset.abs();
     let hours = abs.floor() as i32;
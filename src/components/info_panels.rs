@@ -7,19 +7,22 @@ pub fn InfoPanels(
     meetings: ReadSignal<Vec<Meeting>>,
     set_meetings: WriteSignal<Vec<Meeting>>,
     selected_slot: ReadSignal<Option<SelectedSlot>>,
+    set_selected_slot: WriteSignal<Option<SelectedSlot>>,
     active_zones: ReadSignal<ActiveTimezones>,
-    theme: Signal<&'static ThemeColors>,
+    reference: Signal<Reference>,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
 ) -> impl IntoView {
     let overlap_slots = move || {
         let z = active_zones.get();
-        (0u32..24).filter(|&h| is_full_overlap_utc(h as f64, &z.zones)).collect::<Vec<_>>()
+        (0u32..24).filter(|&h| is_full_overlap_utc(h as f64, z.zones())).collect::<Vec<_>>()
     };
 
     let meetings_in_overlap = move || {
         let z = active_zones.get();
         let m = meetings.get();
         m.iter().filter(|meeting| {
-            is_full_overlap_utc(meeting.utc_hour as f64, &z.zones)
+            is_full_overlap_utc(meeting.utc_hour as f64, z.zones())
         }).cloned().collect::<Vec<_>>()
     };
 
@@ -27,22 +30,22 @@ pub fn InfoPanels(
         let z = active_zones.get();
         let m = meetings.get();
         m.iter().filter(|meeting| {
-            !is_full_overlap_utc(meeting.utc_hour as f64, &z.zones)
+            !is_full_overlap_utc(meeting.utc_hour as f64, z.zones())
         }).cloned().collect::<Vec<_>>()
     };
 
     view! {
         {move || {
-            let t = *theme.get();
-            let z = active_zones.get();
+            let t = THEME_VARS;
             let slots = overlap_slots();
             let in_overlap = meetings_in_overlap();
             let outside = meetings_outside();
-
-            let ref_offset = z.zones[0].utc_offset;
+            let ref_zone = reference.get();
+            let tf = time_format.get();
+            let l = lang.get();
+            let ref_offset = ref_zone.utc_offset;
             let overlap_hours_str = slots.iter().map(|&h| {
-                let local = utc_to_local(h as f64, ref_offset);
-                format!("{:02}:00", local.floor() as u32 % 24)
+                tf.time(utc_to_local(h as f64, ref_offset))
             }).collect::<Vec<_>>().join(", ");
 
             view! {
@@ -53,17 +56,17 @@ pub fn InfoPanels(
                         t.success_bg, t.success_border
                     )>
                         <h3 style=format!("font-weight: 600; font-size: 0.75rem; margin-bottom: 4px; color: {}", t.success_text_dark)>
-                            {format!("Overlap Window: {} hours", slots.len())}
+                            {l.fill(Msg::OverlapWindow, &[&l.count(Count::Hours, slots.len())])}
                         </h3>
                         <p style=format!("font-size: 0.75rem; color: {}", t.success_text)>
                             {if slots.is_empty() {
-                                "No overlap hours found".to_string()
+                                l.t(Msg::NoOverlapHours).to_string()
                             } else {
-                                format!("{} ({} timezone)", overlap_hours_str, z.zones[0].short_name)
+                                l.fill(Msg::InTimezone, &[&overlap_hours_str, ref_zone.short_name])
                             }}
                         </p>
                         <p style=format!("font-size: 0.75rem; margin-top: 4px; color: {}", t.success_text)>
-                            {format!("{} meeting(s) in overlap window", in_overlap.len())}
+                            {l.fill(Msg::MeetingsInOverlap, &[&l.count(Count::Meetings, in_overlap.len())])}
                         </p>
                     </div>
 
@@ -75,7 +78,7 @@ pub fn InfoPanels(
                                 t.warning_bg, t.warning_border
                             )>
                                 <h3 style=format!("font-weight: 600; font-size: 0.75rem; margin-bottom: 8px; color: {}", t.warning_text_dark)>
-                                    {format!("{} meeting(s) outside overlap", outside.len())}
+                                    {l.fill(Msg::MeetingsOutsideOverlap, &[&l.count(Count::Meetings, outside.len())])}
                                 </h3>
                                 {outside.iter().map(|m| {
                                     let meeting_id = m.id;
@@ -87,7 +90,7 @@ pub fn InfoPanels(
                                             t.warning_text
                                         )>
                                             <span style="overflow: hidden; text-overflow: ellipsis; white-space: nowrap">
-                                                {format!("{:02}:00 {} - {}", local_hour.floor() as u32 % 24, z.zones[0].short_name, title)}
+                                                {format!("{} {} - {}", tf.time(local_hour), ref_zone.short_name, title)}
                                             </span>
                                             <button
                                                 style=format!("flex-shrink: 0; cursor: pointer; background: none; border: none; color: {}", t.warning_text)
@@ -109,10 +112,13 @@ pub fn InfoPanels(
                     // Selected slot detail
                     <SlotDetail
                         selected_slot=selected_slot
+                        set_selected_slot=set_selected_slot
                         meetings=meetings
                         set_meetings=set_meetings
                         active_zones=active_zones
-                        theme=theme
+                        reference=reference
+                        time_format=time_format
+                        lang=lang
                     />
                 </div>
             }
@@ -1,11 +1,26 @@
 pub mod clock;
+pub mod clock_change_notice;
 pub mod clock_segment;
 pub mod now_highlight;
 pub mod center_display;
 pub mod controls;
+pub mod config_transfer;
 pub mod info_panels;
+pub mod overlap_analytics;
 pub mod slot_detail;
+pub mod storage_notice;
+pub mod theme_editor;
+pub mod timeline;
+pub mod toast;
+pub mod week_view;
+pub mod workspaces;
 
 pub use clock::Clock;
+pub use clock_change_notice::ClockChangeNotice;
 pub use controls::{Header, TimezoneConfigurator};
 pub use info_panels::InfoPanels;
+pub use overlap_analytics::OverlapAnalytics;
+pub use storage_notice::StorageNotice;
+pub use timeline::Timeline;
+pub use toast::UndoToast;
+pub use week_view::WeekView;
@@ -5,45 +5,69 @@ use crate::modules::*;
 pub fn NowHighlight(
     current_utc: ReadSignal<f64>,
     active_zones: ReadSignal<ActiveTimezones>,
-    theme: ThemeColors,
+    ref_offset: Memo<f64>,
+    local_zone: Option<&'static str>,
+    lang: Signal<Language>,
 ) -> impl IntoView {
-    let highlight_color = theme.now_highlight;
+    let ring_count = Memo::new(move |_| active_zones.with(|z| z.zones().len()));
+    let geos = Memo::new(move |_| compute_ring_geometries(ring_count.get()));
+    let outer_hour = Memo::new(move |_| utc_to_local(current_utc.get(), ref_offset.get()));
+    // Only changes once an hour, so the outlines stay put between ticks
+    let hour_int = Memo::new(move |_| outer_hour.get().floor() as u32);
 
-    view! {
-        {move || {
-            let zones = active_zones.get();
-            let n = zones.zones.len();
-            let geos = compute_ring_geometries(n);
-            let ref_offset = zones.zones[0].utc_offset;
-            let outer_hour = utc_to_local(current_utc.get(), ref_offset);
-            let hour_int = outer_hour.floor() as u32;
-            let exact_angle = get_hour_angle(outer_hour);
-
-            let highlight_paths: Vec<String> = geos.iter().map(|geo| {
-                segment_path(CX, CY, geo.outer_r, geo.inner_r, hour_int as f64, (hour_int + 1) as f64)
-            }).collect();
+    let line_coords = Memo::new(move |_| {
+        let exact_angle = get_hour_angle(outer_hour.get());
+        let outer_r = geos.with(|g| g[0].outer_r);
+        let inner = polar_to_cartesian(CX, CY, CENTER_R, exact_angle);
+        let outer = polar_to_cartesian(CX, CY, outer_r, exact_angle);
+        (inner, outer)
+    });
 
-            let (line_inner_x, line_inner_y) = polar_to_cartesian(CX, CY, CENTER_R, exact_angle);
-            let (line_outer_x, line_outer_y) = polar_to_cartesian(CX, CY, geos[0].outer_r, exact_angle);
+    // "You are here": a dot where the now line crosses the viewer's own ring
+    let here = Memo::new(move |_| {
+        let ring = active_zones.with(|z| z.zones().iter().position(|tz| Some(tz.id) == local_zone))?;
+        let geo = geos.with(|g| g.get(ring).copied())?;
+        Some(polar_to_cartesian(CX, CY, (geo.outer_r + geo.inner_r) / 2.0, get_hour_angle(outer_hour.get())))
+    });
 
-            view! {
-                <g style="pointer-events: none">
-                    {highlight_paths.into_iter().map(|path| {
-                        view! {
-                            <path d=path fill="none" stroke=highlight_color stroke-width="3" />
-                        }
-                    }).collect_view()}
-                    <line
-                        x1=line_inner_x
-                        y1=line_inner_y
-                        x2=line_outer_x
-                        y2=line_outer_y
-                        stroke="#ec4899"
-                        stroke-width="3"
-                        stroke-linecap="round"
-                    />
-                </g>
-            }
-        }}
+    view! {
+        <g style="pointer-events: none">
+            <For
+                each=move || 0..ring_count.get()
+                key=|ring_idx| *ring_idx
+                let:ring_idx
+            >
+                <path
+                    d=move || {
+                        let h = hour_int.get();
+                        geos.with(|g| g.get(ring_idx).map(|geo| {
+                            segment_path(CX, CY, geo.outer_r, geo.inner_r, h as f64, (h + 1) as f64)
+                        })).unwrap_or_default()
+                    }
+                    fill="none"
+                    style=format!("stroke: {}", THEME_VARS.now_highlight)
+                    stroke-width="3"
+                />
+            </For>
+            <line
+                x1=move || line_coords.get().0 .0
+                y1=move || line_coords.get().0 .1
+                x2=move || line_coords.get().1 .0
+                y2=move || line_coords.get().1 .1
+                style=format!("stroke: {}", THEME_VARS.now_line)
+                stroke-width="3"
+                stroke-linecap="round"
+            />
+            {move || here.get().map(|(x, y)| view! {
+                <circle
+                    cx=x
+                    cy=y
+                    r="5"
+                    style=format!("fill: {}; stroke: {}", THEME_VARS.now_highlight, THEME_VARS.svg_bg)
+                    stroke-width="2"
+                    aria-label=move || lang.get().t(Msg::YouAreHere)
+                />
+            })}
+        </g>
     }
 }
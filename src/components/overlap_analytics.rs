@@ -0,0 +1,155 @@
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::modules::*;
+
+const CHART_WIDTH: f64 = 732.0;
+const CHART_HEIGHT: f64 = 140.0;
+/// Left margin for the hour axis
+const AXIS_WIDTH: f64 = 36.0;
+/// Bottom margin for month labels
+const MONTHS_HEIGHT: f64 = 16.0;
+
+/// Fully overlapping working hours for each day of a year, following daylight
+/// saving time and weekends, with the weeks of lowest overlap highlighted.
+/// Only computed while expanded.
+#[component]
+pub fn OverlapAnalytics(
+    active_zones: ReadSignal<ActiveTimezones>,
+    reference: Signal<Reference>,
+    reference_tz: Memo<Option<&'static TimezoneEntry>>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    let t = THEME_VARS;
+    let (open, set_open) = signal(false);
+    let (year, set_year) = signal(civil_from_days(now_epoch_hours().div_euclid(24.0) as i64).0);
+
+    let days = Memo::new(move |_| {
+        if !open.get() {
+            return Vec::new();
+        }
+        let (tz, offset) = (reference_tz.get(), reference.get().utc_offset);
+        active_zones.with(|z| year_overlap(z.zones(), year.get(), |day| match tz {
+            Some(tz) => local_day_start(Some(tz), day),
+            None => day as f64 * 24.0 - offset,
+        }))
+    });
+    let worst = Memo::new(move |_| days.with(|d| worst_periods(d)));
+
+    let chart = move || {
+        let l = lang.get();
+        let days = days.get();
+        let first = days.first()?.day;
+        let count = days.len() as f64;
+        let max = days.iter().map(|d| d.hours).fold(1.0, f64::max).ceil();
+        let plot_height = CHART_HEIGHT - MONTHS_HEIGHT;
+        let bar = (CHART_WIDTH - AXIS_WIDTH) / count;
+        let x = move |day: i64| AXIS_WIDTH + (day - first) as f64 * bar;
+
+        let highlights = worst.get().into_iter().map(|(start, end)| view! {
+            <rect
+                x=x(start)
+                y="0"
+                width=(end - start + 1) as f64 * bar
+                height=plot_height
+                style=format!("fill: {}; stroke: {}", t.warning_bg, t.warning_border)
+            />
+        }).collect_view();
+        let bars = days.iter().filter(|d| d.hours > 0.0).map(|d| {
+            let height = d.hours / max * (plot_height - 4.0);
+            let color = if weekday(d.day) >= SATURDAY { t.text_muted } else { t.working_hours };
+            view! {
+                <rect x=x(d.day) y=plot_height - height width=bar height=height style=format!("fill: {}", color) />
+            }
+        }).collect_view();
+        let months = (1..=12).map(|month| {
+            let day = days_from_civil(civil_from_days(first).0, month, 1);
+            view! {
+                <text x=x(day) + 2.0 y=CHART_HEIGHT - 4.0 font-size="9" style=format!("fill: {}", t.text_muted)>
+                    {format_month(l.code(), day)}
+                </text>
+                <line x1=x(day) y1="0" x2=x(day) y2=CHART_HEIGHT style=format!("stroke: {}", t.ring_divider) stroke-width="0.5" />
+            }
+        }).collect_view();
+
+        Some(view! {
+            <svg
+                viewBox=format!("0 0 {} {}", CHART_WIDTH, CHART_HEIGHT)
+                width="100%"
+                role="img"
+                aria-label=l.t(Msg::YearOverlapChart)
+                style="display: block"
+            >
+                {highlights}
+                {bars}
+                {months}
+                <text x="0" y="9" font-size="9" style=format!("fill: {}", t.text_muted)>{l.short_hours(max, 0)}</text>
+                <text x="0" y=plot_height font-size="9" style=format!("fill: {}", t.text_muted)>{l.short_hours(0.0, 0)}</text>
+                <line x1=AXIS_WIDTH y1=plot_height x2=CHART_WIDTH y2=plot_height style=format!("stroke: {}", t.card_border) stroke-width="1" />
+            </svg>
+        })
+    };
+
+    let summary = move || {
+        let l = lang.get();
+        let weeks = days.with(|d| weekly_overlap(d));
+        if weeks.is_empty() {
+            return None;
+        }
+        let average = weeks.iter().map(|w| w.1).sum::<f64>() / weeks.len() as f64;
+        let lowest = weeks.iter().map(|w| w.1).fold(f64::INFINITY, f64::min);
+        let periods = worst.get().iter().map(|&(start, end)| {
+            format!("{} \u{2013} {}", format_day(l.code(), start), format_day(l.code(), end))
+        }).collect::<Vec<_>>();
+        let worst_line = if periods.is_empty() {
+            l.t(Msg::OverlapSteady).to_string()
+        } else {
+            l.fill(Msg::WorstPeriods, &[&l.short_hours(lowest, 1), &periods.join(", ")])
+        };
+        Some(view! {
+            <p>{l.fill(Msg::YearOverlapAverage, &[&l.short_hours(average, 1)])}</p>
+            <p style=format!("color: {}", t.warning_text)>{worst_line}</p>
+        })
+    };
+
+    let year_button = move |msg: Msg, label: &'static str, step: i32| view! {
+        <button
+            title=move || lang.get().t(msg)
+            aria-label=move || lang.get().t(msg)
+            style=format!(
+                "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 2px 10px; font-size: 12px; cursor: pointer",
+                t.button_secondary_bg, t.text_secondary, t.card_border
+            )
+            on:click=move |_| set_year.update(|y| *y += step)
+        >
+            {label}
+        </button>
+    };
+
+    view! {
+        <details
+            style=format!(
+                "margin: 0 32px 32px; padding: 12px 16px; border-radius: 8px; background: {}; border: 1px solid {}; color: {}",
+                t.card_bg, t.card_border, t.text_primary
+            )
+            // Fires after open and close, possibly once for several quick clicks
+            on:toggle=move |ev| {
+                if let Some(details) = ev.target().and_then(|t| t.dyn_into::<web_sys::HtmlDetailsElement>().ok()) {
+                    set_open.set(details.open());
+                }
+            }
+        >
+            <summary style="cursor: pointer; font-weight: 600; font-size: 0.9rem">
+                {move || lang.get().t(Msg::YearOverlapTitle)}
+            </summary>
+            <div style="display: flex; flex-direction: column; gap: 8px; margin-top: 12px; font-size: 0.8rem">
+                <div style="display: flex; align-items: center; gap: 8px">
+                    {year_button(Msg::PreviousYear, "\u{2039}", -1)}
+                    <span style="font-weight: 600; min-width: 3em; text-align: center">{move || year.get()}</span>
+                    {year_button(Msg::NextYear, "\u{203A}", 1)}
+                </div>
+                {chart}
+                {summary}
+            </div>
+        </details>
+    }
+}
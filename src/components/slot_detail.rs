@@ -4,43 +4,48 @@ use crate::modules::*;
 #[component]
 pub fn SlotDetail(
     selected_slot: ReadSignal<Option<SelectedSlot>>,
+    set_selected_slot: WriteSignal<Option<SelectedSlot>>,
     meetings: ReadSignal<Vec<Meeting>>,
     set_meetings: WriteSignal<Vec<Meeting>>,
     active_zones: ReadSignal<ActiveTimezones>,
-    theme: Signal<&'static ThemeColors>,
+    reference: Signal<Reference>,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
 ) -> impl IntoView {
     let (new_title, set_new_title) = signal(String::new());
     let (new_essential, set_new_essential) = signal(false);
+    let (new_organizer, set_new_organizer) = signal(None::<&'static str>);
+
+    // Inline edit form state; `editing` holds the id of the meeting being edited
+    let (editing, set_editing) = signal(None::<u32>);
+    let (edit_title, set_edit_title) = signal(String::new());
+    let (edit_essential, set_edit_essential) = signal(false);
+    let (edit_hour, set_edit_hour) = signal(0u32);
 
     view! {
         {move || {
             let slot = selected_slot.get()?;
-            let t = *theme.get();
+            let t = THEME_VARS;
             let z = active_zones.get();
+            let tf = time_format.get();
+            let l = lang.get();
 
             // Build time label dynamically
-            let time_label = slot.local_hours.iter().zip(z.zones.iter()).map(|(h, tz)| {
-                let hour_part = h.floor() as u32 % 24;
-                let frac = tz.utc_offset % 1.0;
-                if frac.abs() < 0.01 {
-                    format!("{:02}:00 {}", hour_part, tz.short_name)
-                } else {
-                    let mins = (frac.abs() * 60.0).round() as u32;
-                    format!("{:02}:{:02} {}", hour_part, mins, tz.short_name)
-                }
+            let time_label = slot.local_hours.iter().zip(z.zones().iter()).map(|(h, tz)| {
+                format!("{} {}", tf.time(*h), tz.short_name)
             }).collect::<Vec<_>>().join(" = ");
 
             // Check overlap
-            let full_overlap = is_full_overlap_utc(slot.utc_hour as f64, &z.zones);
+            let full_overlap = is_full_overlap_utc(slot.utc_hour as f64, z.zones());
 
             let overlap_msg = if full_overlap {
-                (format!("\u{2713} All timezones in working hours \u{2014} ideal!"), t.success_text)
+                (l.t(Msg::AllInWorkingHours).to_string(), t.success_text)
             } else {
-                let outside: Vec<&str> = z.zones.iter().filter_map(|tz| {
+                let outside: Vec<&str> = z.zones().iter().filter_map(|tz| {
                     let local = utc_to_local(slot.utc_hour as f64, tz.utc_offset);
-                    if !is_work_hour(local) { Some(tz.name) } else { None }
+                    if !tz.is_work_hour(local) { Some(l.zone_name(tz)) } else { None }
                 }).collect();
-                (format!("\u{26A0} {} outside working hours", outside.join(", ")), t.warning_text)
+                (l.fill(Msg::OutsideWorkingHours, &[&outside.join(", ")]), t.warning_text)
             };
 
             let slot_meetings: Vec<Meeting> = meetings.get().into_iter()
@@ -48,6 +53,9 @@ pub fn SlotDetail(
                 .collect();
 
             let utc_hour_for_add = slot.utc_hour;
+            let organizer = new_organizer.get()
+                .filter(|id| z.zones().iter().any(|tz| tz.id == *id))
+                .unwrap_or(z.zones()[0].id);
 
             Some(view! {
                 <div style=format!(
@@ -66,32 +74,142 @@ pub fn SlotDetail(
                         let meeting_id = m.id;
                         let dot_color = if m.essential { t.meeting_essential } else { t.meeting_non_essential };
                         let title = m.title.clone();
+                        let organizer_name = z.zones().iter()
+                            .find(|tz| tz.id == m.organizer_zone)
+                            .map(|tz| tz.short_name)
+                            .unwrap_or("");
+                        let row_style = format!(
+                            "display: flex; align-items: center; justify-content: space-between; padding: 8px; border-radius: 4px; margin-bottom: 6px; gap: 8px; background: {}; border: 1px solid {}",
+                            t.background, t.card_border
+                        );
+                        let input_style = format!(
+                            "font-size: 0.75rem; padding: 4px 6px; border-radius: 4px; border: 1px solid {}; background: {}; color: {}; outline: none",
+                            t.input_border, t.input_bg, t.text_primary
+                        );
+                        let icon_button_style = format!(
+                            "font-size: 0.75rem; flex-shrink: 0; cursor: pointer; background: none; border: none; color: {}",
+                            t.text_secondary
+                        );
+
+                        if editing.get() == Some(meeting_id) {
+                            let ref_zone = reference.get();
+                            return view! {
+                                <div style=format!("{}; flex-direction: column; align-items: stretch", row_style)>
+                                    <input
+                                        type="text"
+                                        style=input_style.clone()
+                                        prop:value=move || edit_title.get()
+                                        on:input=move |ev| set_edit_title.set(event_target_value(&ev))
+                                    />
+                                    <div style="display: flex; align-items: center; gap: 8px; flex-wrap: wrap">
+                                        <select
+                                            style=input_style
+                                            prop:value=move || edit_hour.get().to_string()
+                                            on:change=move |ev| {
+                                                if let Ok(h) = event_target_value(&ev).parse::<u32>() {
+                                                    set_edit_hour.set(h % 24);
+                                                }
+                                            }
+                                        >
+                                            {(0u32..24).map(|h| {
+                                                let label = format!(
+                                                    "{} {} ({} UTC)",
+                                                    tf.time(utc_to_local(h as f64, ref_zone.utc_offset)),
+                                                    ref_zone.short_name,
+                                                    tf.time(h as f64)
+                                                );
+                                                view! {
+                                                    <option value=h.to_string() selected=move || edit_hour.get() == h>{label}</option>
+                                                }
+                                            }).collect_view()}
+                                        </select>
+                                        <label style=format!("font-size: 0.75rem; display: flex; align-items: center; gap: 4px; color: {}", t.text_secondary)>
+                                            <input
+                                                type="checkbox"
+                                                prop:checked=move || edit_essential.get()
+                                                on:change=move |ev| set_edit_essential.set(event_target_checked(&ev))
+                                            />
+                                            {l.t(Msg::Essential)}
+                                        </label>
+                                    </div>
+                                    <div style="display: flex; justify-content: flex-end; gap: 8px">
+                                        <button
+                                            style=icon_button_style
+                                            on:click=move |_| set_editing.set(None)
+                                        >
+                                            {l.t(Msg::Cancel)}
+                                        </button>
+                                        <button
+                                            style=format!(
+                                                "font-size: 0.75rem; border: none; border-radius: 4px; padding: 4px 10px; cursor: pointer; background: {}; color: {}",
+                                                t.button_primary_bg, t.button_primary_text
+                                            )
+                                            on:click=move |_| {
+                                                let title = edit_title.get_untracked();
+                                                if title.trim().is_empty() {
+                                                    return;
+                                                }
+                                                let new_hour = edit_hour.get_untracked();
+                                                set_meetings.update(|ms| {
+                                                    if let Some(m) = ms.iter_mut().find(|m| m.id == meeting_id) {
+                                                        m.title = title;
+                                                        m.essential = edit_essential.get_untracked();
+                                                        m.utc_hour = new_hour;
+                                                    }
+                                                });
+                                                set_editing.set(None);
+                                                // Follow the meeting if it moved to another hour
+                                                if new_hour != utc_hour_for_add {
+                                                    set_selected_slot.set(Some(active_zones.with_untracked(|z| SelectedSlot::at(new_hour, z))));
+                                                }
+                                            }
+                                        >
+                                            {l.t(Msg::Save)}
+                                        </button>
+                                    </div>
+                                </div>
+                            }.into_any();
+                        }
+
+                        let edit_meeting = m.clone();
                         view! {
-                            <div style=format!(
-                                "display: flex; align-items: center; justify-content: space-between; padding: 8px; border-radius: 4px; margin-bottom: 6px; gap: 8px; background: {}; border: 1px solid {}",
-                                t.background, t.card_border
-                            )>
+                            <div style=row_style>
                                 <span style=format!("font-size: 0.75rem; display: flex; align-items: center; gap: 8px; overflow: hidden; text-overflow: ellipsis; white-space: nowrap; color: {}", t.text_primary)>
                                     <span style=format!("width: 8px; height: 8px; border-radius: 50%; flex-shrink: 0; background: {}", dot_color) />
                                     {title}
+                                    <span style=format!("color: {}", t.text_muted)>{organizer_name}</span>
+                                </span>
+                                <span style="display: flex; gap: 4px; flex-shrink: 0">
+                                    <button
+                                        style=icon_button_style
+                                        title=l.t(Msg::EditMeeting)
+                                        on:click=move |_| {
+                                            set_edit_title.set(edit_meeting.title.clone());
+                                            set_edit_essential.set(edit_meeting.essential);
+                                            set_edit_hour.set(edit_meeting.utc_hour);
+                                            set_editing.set(Some(edit_meeting.id));
+                                        }
+                                    >
+                                        "\u{270E}"
+                                    </button>
+                                    <button
+                                        style=format!("font-size: 0.75rem; flex-shrink: 0; cursor: pointer; background: none; border: none; color: {}", t.warning_text)
+                                        on:click=move |_| {
+                                            set_meetings.update(|m| m.retain(|meeting| meeting.id != meeting_id));
+                                        }
+                                    >
+                                        "\u{2715}"
+                                    </button>
                                 </span>
-                                <button
-                                    style=format!("font-size: 0.75rem; flex-shrink: 0; cursor: pointer; background: none; border: none; color: {}", t.warning_text)
-                                    on:click=move |_| {
-                                        set_meetings.update(|m| m.retain(|meeting| meeting.id != meeting_id));
-                                    }
-                                >
-                                    "\u{2715}"
-                                </button>
                             </div>
-                        }
+                        }.into_any()
                     }).collect_view()}
 
                     // Add meeting form
                     <div style="margin-top: 8px; display: flex; flex-direction: column; gap: 8px">
                         <input
                             type="text"
-                            placeholder="Meeting title"
+                            placeholder=l.t(Msg::MeetingTitle)
                             style=format!(
                                 "font-size: 0.75rem; padding: 6px 8px; border-radius: 4px; border: 1px solid {}; background: {}; color: {}; outline: none",
                                 t.input_border, t.input_bg, t.text_primary
@@ -99,13 +217,35 @@ pub fn SlotDetail(
                             prop:value=move || new_title.get()
                             on:input=move |ev| set_new_title.set(event_target_value(&ev))
                         />
+                        <label style=format!("font-size: 0.75rem; display: flex; align-items: center; gap: 6px; color: {}", t.text_secondary)>
+                            {l.t(Msg::Organizer)}
+                            <select
+                                style=format!(
+                                    "font-size: 0.75rem; padding: 2px 4px; border-radius: 4px; border: 1px solid {}; background: {}; color: {}",
+                                    t.input_border, t.input_bg, t.text_primary
+                                )
+                                prop:value=organizer
+                                on:change=move |ev| {
+                                    let val = event_target_value(&ev);
+                                    let id = active_zones.with_untracked(|z| z.zones().iter().find(|tz| tz.id == val).map(|tz| tz.id));
+                                    set_new_organizer.set(id);
+                                }
+                            >
+                                {z.zones().iter().map(|tz| {
+                                    let tz_id = tz.id;
+                                    view! {
+                                        <option value=tz_id selected=tz_id == organizer>{l.zone_name(tz)}</option>
+                                    }
+                                }).collect_view()}
+                            </select>
+                        </label>
                         <label style=format!("font-size: 0.75rem; display: flex; align-items: center; gap: 6px; color: {}", t.text_secondary)>
                             <input
                                 type="checkbox"
                                 prop:checked=move || new_essential.get()
                                 on:change=move |ev| set_new_essential.set(event_target_checked(&ev))
                             />
-                            "Essential for cross-timezone collaboration?"
+                            {l.t(Msg::EssentialQuestion)}
                         </label>
                         <button
                             style=format!(
@@ -121,13 +261,14 @@ pub fn SlotDetail(
                                         utc_hour: utc_hour_for_add,
                                         title,
                                         essential: new_essential.get(),
+                                        organizer_zone: organizer,
                                     }));
                                     set_new_title.set(String::new());
                                     set_new_essential.set(false);
                                 }
                             }
                         >
-                            "+ Add Meeting at This Time"
+                            {l.t(Msg::AddMeeting)}
                         </button>
                     </div>
                 </div>
@@ -0,0 +1,37 @@
+use leptos::prelude::*;
+use crate::modules::*;
+
+/// Banner explaining that settings are not being saved, e.g. storage disabled
+/// in private browsing or the quota is full. Stays until dismissed.
+#[component]
+pub fn StorageNotice(
+    error: ReadSignal<Option<StoreError>>,
+    set_error: WriteSignal<Option<StoreError>>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    view! {
+        {move || {
+            let l = lang.get();
+            let message = error.with(|e| e.as_ref().map(|e| l.store_error(e)))?;
+            let t = THEME_VARS;
+            Some(view! {
+                <div
+                    role="alert"
+                    style=format!(
+                        "position: fixed; left: 50%; top: 16px; transform: translateX(-50%); display: flex; align-items: center; gap: 16px; max-width: 90vw; padding: 10px 16px; border-radius: 8px; font-size: 0.8rem; z-index: 10; background: {}; border: 1px solid {}; color: {}",
+                        t.warning_bg, t.warning_border, t.warning_text_dark
+                    )
+                >
+                    <span>{message}</span>
+                    <button
+                        style=format!("background: none; border: none; cursor: pointer; font-size: 0.8rem; color: {}", t.warning_text_dark)
+                        title=l.t(Msg::Dismiss)
+                        on:click=move |_| set_error.set(None)
+                    >
+                        "\u{2715}"
+                    </button>
+                </div>
+            })
+        }}
+    }
+}
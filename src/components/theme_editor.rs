@@ -0,0 +1,237 @@
+use leptos::prelude::*;
+use crate::modules::*;
+use super::config_transfer::{download_json, read_picked_file};
+
+/// Editor for a custom theme. The draft is previewed across the whole app
+/// while it is open; edits apply to the palette of the current light/dark mode.
+#[component]
+pub fn ThemeEditor(
+    draft: ReadSignal<Option<ThemeDraft>>,
+    set_draft: WriteSignal<Option<ThemeDraft>>,
+    custom_themes: ReadSignal<Vec<CustomTheme>>,
+    set_custom_themes: WriteSignal<Vec<CustomTheme>>,
+    theme_name: ReadSignal<ThemeName>,
+    set_theme_name: WriteSignal<ThemeName>,
+    mode: Signal<Mode>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    let (import_errors, set_import_errors) = signal(Vec::<ImportError>::new());
+
+    // Invalid hex input is ignored and the field snaps back on the next render
+    let set_color = move |key: &'static str, raw: String| {
+        let Some(color) = normalize_color(&raw) else { return };
+        let mode = mode.get_untracked();
+        set_draft.update(|d| {
+            if let Some(d) = d {
+                d.palette_mut(mode).set_color(key, color);
+            }
+        });
+    };
+
+    let save = move |_| {
+        let Some(edited) = draft.with_untracked(|d| d.as_ref().map(ThemeDraft::to_theme)) else { return };
+        let id = edited.id;
+        set_custom_themes.update(|themes| match themes.iter_mut().find(|t| t.id == id) {
+            Some(existing) => *existing = edited,
+            None => themes.push(edited),
+        });
+        set_theme_name.set(ThemeName::Custom(id));
+        set_draft.set(None);
+    };
+
+    let delete = move |_| {
+        let Some(edited) = draft.get_untracked() else { return };
+        let confirmed = web_sys::window()
+            .and_then(|w| w.confirm_with_message(&lang.get_untracked().fill(Msg::ConfirmDeleteTheme, &[&edited.name])).ok())
+            .unwrap_or(false);
+        if !confirmed {
+            return;
+        }
+        set_custom_themes.update(|themes| themes.retain(|t| t.id != edited.id));
+        // Other workspaces using it fall back to Minimalist when shown
+        if theme_name.get_untracked() == ThemeName::Custom(edited.id) {
+            set_theme_name.set(ThemeName::Minimalist);
+        }
+        set_draft.set(None);
+    };
+
+    let start_from = move |key: String| {
+        let Some(base) = ThemeName::from_key(&key) else { return };
+        let themes = custom_themes.get_untracked();
+        set_draft.update(|d| {
+            if let Some(d) = d {
+                let fresh = CustomTheme::from_base(d.id, &d.name, base, &themes);
+                d.light = fresh.light.into();
+                d.dark = fresh.dark.into();
+            }
+        });
+    };
+
+    let on_export = move |_| {
+        if let Some(d) = draft.get_untracked() {
+            download_json("tz-clock-theme.json", &export_theme(&d));
+        }
+    };
+
+    // An imported theme opens as a new draft; it is only kept once saved
+    let on_file = move |ev: leptos::ev::Event| {
+        read_picked_file(&ev, move |text| match parse_theme(&text) {
+            Ok(mut imported) => {
+                imported.id = custom_themes.with_untracked(|c| next_theme_id(c));
+                set_import_errors.set(Vec::new());
+                set_draft.set(Some(imported));
+            }
+            Err(errors) => set_import_errors.set(errors),
+        });
+    };
+
+    view! {
+        {move || {
+            let d = draft.get()?;
+            let t = THEME_VARS;
+            let l = lang.get();
+            let current_mode = mode.get();
+            let palette = d.palette(current_mode).clone();
+            let is_saved = custom_themes.with(|c| c.iter().any(|x| x.id == d.id));
+            let bases: Vec<(String, String)> = ThemeName::ALL.iter()
+                .map(|tn| (tn.key(), tn.label().to_string()))
+                .chain(custom_themes.with(|c| c.iter().map(|x| (ThemeName::Custom(x.id).key(), x.name.clone())).collect::<Vec<_>>()))
+                .collect();
+
+            let button_style = format!(
+                "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 4px 10px; font-size: 11px; cursor: pointer",
+                t.button_secondary_bg, t.text_secondary, t.card_border
+            );
+            let primary_style = format!(
+                "background: {}; color: {}; border: none; border-radius: 6px; padding: 4px 10px; font-size: 11px; cursor: pointer",
+                t.button_primary_bg, t.button_primary_text
+            );
+            let input_style = format!(
+                "border: 1px solid {}; background: {}; color: {}; border-radius: 4px; padding: 2px 4px; font-size: 11px",
+                t.input_border, t.input_bg, t.text_primary
+            );
+
+            let issues = contrast_issues(&palette);
+            let contrast = if issues.is_empty() {
+                view! {
+                    <p style=format!("font-size: 11px; color: {}", t.success_text)>{l.t(Msg::ContrastOk)}</p>
+                }.into_any()
+            } else {
+                view! {
+                    <ul style=format!("font-size: 11px; margin-left: 16px; color: {}", t.warning_text)>
+                        {issues.iter().map(|i| {
+                            let ratio = format!("{:.1}", i.ratio);
+                            let required = MIN_TEXT_CONTRAST.to_string();
+                            view! { <li>{l.fill(Msg::LowContrast, &[i.foreground, i.background, &ratio, &required])}</li> }
+                        }).collect_view()}
+                    </ul>
+                }.into_any()
+            };
+
+            let errors = import_errors.get();
+            let import_report = (!errors.is_empty()).then(|| view! {
+                <div style=format!(
+                    "padding: 8px 12px; border-radius: 6px; font-size: 11px; background: {}; border: 1px solid {}; color: {}",
+                    t.warning_bg, t.warning_border, t.warning_text_dark
+                )>
+                    <strong>{l.fill(Msg::ImportFailed, &[&l.count(Count::Problems, errors.len())])}</strong>
+                    <ul style="margin: 4px 0 0 16px">
+                        {errors.iter().map(|e| view! { <li>{l.import_error(e)}</li> }).collect_view()}
+                    </ul>
+                </div>
+            });
+
+            Some(view! {
+                <div style=format!(
+                    "display: flex; flex-direction: column; gap: 8px; padding: 12px; border-radius: 8px; background: {}; border: 1px solid {}",
+                    t.card_bg, t.card_border
+                )>
+                    <div style=format!("display: flex; gap: 12px; align-items: center; flex-wrap: wrap; font-size: 11px; color: {}", t.text_secondary)>
+                        <label style="display: flex; align-items: center; gap: 6px">
+                            {l.t(Msg::ThemeNameLabel)}
+                            <input
+                                type="text"
+                                style=input_style.clone()
+                                prop:value=d.name.clone()
+                                on:change=move |ev| {
+                                    let name = event_target_value(&ev).trim().to_string();
+                                    if !name.is_empty() {
+                                        set_draft.update(|d| if let Some(d) = d { d.name = name });
+                                    }
+                                }
+                            />
+                        </label>
+                        <label style="display: flex; align-items: center; gap: 6px">
+                            {l.t(Msg::StartFrom)}
+                            <select
+                                style=input_style.clone()
+                                prop:value=""
+                                on:change=move |ev| start_from(event_target_value(&ev))
+                            >
+                                <option value="" selected=true>"\u{2014}"</option>
+                                {bases.into_iter().map(|(key, label)| view! {
+                                    <option value=key>{label}</option>
+                                }).collect_view()}
+                            </select>
+                        </label>
+                    </div>
+                    <p style=format!("font-size: 11px; color: {}", t.text_muted)>
+                        {l.t(if current_mode == Mode::Light { Msg::EditingLight } else { Msg::EditingDark })}
+                    </p>
+
+                    <div style="display: grid; grid-template-columns: repeat(auto-fill, minmax(220px, 1fr)); gap: 4px 12px">
+                        {COLOR_KEYS.into_iter().map(|key| {
+                            let value = palette.color(key).unwrap_or("#000000").to_string();
+                            view! {
+                                <label style=format!("display: flex; align-items: center; gap: 6px; font-size: 11px; color: {}", t.text_secondary)>
+                                    <input
+                                        type="color"
+                                        style="width: 28px; height: 20px; padding: 0; border: none; background: none; cursor: pointer"
+                                        prop:value=value.clone()
+                                        on:change=move |ev| set_color(key, event_target_value(&ev))
+                                    />
+                                    <input
+                                        type="text"
+                                        aria-label=key
+                                        style=format!("{}; width: 64px; font-family: monospace", input_style)
+                                        prop:value=value
+                                        on:change=move |ev| set_color(key, event_target_value(&ev))
+                                    />
+                                    <span style="font-family: monospace">{key}</span>
+                                </label>
+                            }
+                        }).collect_view()}
+                    </div>
+
+                    <div style=format!("display: flex; align-items: center; gap: 4px; flex-wrap: wrap; font-size: 11px; color: {}", t.text_muted)>
+                        {l.t(Msg::RingColorsPreview)}
+                        {(0..MAX_RINGS).map(|slot| view! {
+                            <span style=format!(
+                                "padding: 1px 6px; border-radius: 4px; font-weight: 600; border: 1px solid {}; background: {}; color: {}",
+                                t.card_border, palette.ring_fill(slot), palette.ring_text(slot)
+                            )>
+                                {slot + 1}
+                            </span>
+                        }).collect_view()}
+                    </div>
+
+                    {contrast}
+                    {import_report}
+
+                    <div style="display: flex; gap: 8px; align-items: center; flex-wrap: wrap">
+                        <button style=primary_style on:click=save>{l.t(Msg::Save)}</button>
+                        <button style=button_style.clone() on:click=move |_| set_draft.set(None)>{l.t(Msg::Cancel)}</button>
+                        {is_saved.then(|| view! {
+                            <button style=button_style.clone() on:click=delete>{l.t(Msg::Delete)}</button>
+                        })}
+                        <button style=button_style.clone() on:click=on_export>{l.t(Msg::ExportJson)}</button>
+                        <label style=button_style.clone()>
+                            {l.t(Msg::ImportJson)}
+                            <input type="file" accept="application/json,.json" style="display: none" on:change=on_file />
+                        </label>
+                    </div>
+                </div>
+            })
+        }}
+    }
+}
@@ -0,0 +1,189 @@
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::modules::*;
+
+/// Width of the zone name column
+const LABEL_WIDTH: &str = "112px";
+
+/// The active zones as rows of 24 hour cells, columns aligned to the
+/// reference zone's hours like the dial's segments. Shares selection with
+/// the dial, so clicking a cell opens the same slot detail.
+#[component]
+pub fn Timeline(
+    meetings: ReadSignal<Vec<Meeting>>,
+    selected_slot: ReadSignal<Option<SelectedSlot>>,
+    set_selected_slot: WriteSignal<Option<SelectedSlot>>,
+    current_utc: ReadSignal<f64>,
+    active_zones: ReadSignal<ActiveTimezones>,
+    reference: Signal<Reference>,
+    /// Zone id of the viewer's own zone, marked "you are here" on its row.
+    local_zone: Option<&'static str>,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
+    hatch: Signal<bool>,
+) -> impl IntoView {
+    let t = THEME_VARS;
+    let ref_offset = Memo::new(move |_| reference.get().utc_offset);
+    // (row, column) of the one cell reachable with Tab; arrow keys move it
+    let focus = RwSignal::new((0usize, 0u32));
+
+    // Reference-zone position of "now" as a percentage of the row
+    let now_left = move || utc_to_local(current_utc.get(), ref_offset.get()) / 24.0 * 100.0;
+    let now_line = move || view! {
+        <div
+            aria-hidden="true"
+            style=move || format!(
+                "position: absolute; top: 0; bottom: 0; left: {:.3}%; width: 3px; margin-left: -1.5px; pointer-events: none; background: {}",
+                now_left(), t.now_line
+            )
+        />
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let rows = active_zones.with_untracked(|z| z.zones().len());
+        let Some((row, column)) = dial_neighbor(focus.get_untracked(), &ev.key(), rows) else { return };
+        ev.prevent_default();
+        focus.set((row, column));
+        let target = ev.current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .and_then(|el| el.query_selector(&format!("[data-row=\"{}\"][data-column=\"{}\"]", row, column)).ok().flatten())
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+        if let Some(el) = target {
+            let _ = el.focus();
+        }
+    };
+
+    view! {
+        <div
+            role="group"
+            aria-label=move || lang.get().t(Msg::TimelineLabel)
+            style="width: 100%; overflow-x: auto"
+            on:keydown=on_keydown
+        >
+            <div style="min-width: 720px; display: flex; flex-direction: column; font-size: 10px">
+                // Reference-zone hours
+                <div style="display: flex" aria-hidden="true">
+                    <div style=format!("width: {}; flex-shrink: 0; padding: 2px 6px; font-weight: 600; color: {}", LABEL_WIDTH, t.text_secondary)>
+                        {move || reference.get().short_name}
+                    </div>
+                    <div style="flex: 1; position: relative; display: grid; grid-template-columns: repeat(24, minmax(0, 1fr))">
+                        {move || {
+                            let tf = time_format.get();
+                            (0u32..24).map(|column| view! {
+                                <div style=format!("text-align: center; padding: 2px 0; color: {}", t.text_muted)>
+                                    {tf.compact(column as f64)}
+                                </div>
+                            }).collect_view()
+                        }}
+                    </div>
+                </div>
+
+                {move || {
+                    let zones = active_zones.get();
+                    let reference_offset = ref_offset.get();
+                    let tf = time_format.get();
+                    let l = lang.get();
+                    let striped = hatch.get();
+                    let all_meetings = meetings.get();
+                    let rows = zones.zones().len();
+
+                    zones.zones().iter().enumerate().map(|(row, tz)| {
+                        let is_here = Some(tz.id) == local_zone;
+                        let cells = (0u32..24).map(|column| {
+                            let display_hour = convert_between(column as f64, reference_offset, tz.utc_offset);
+                            let utc_hour = ((column as f64 - reference_offset + 48.0) % 24.0).round() as u32 % 24;
+                            let is_working = (tz.work_start..tz.work_end).contains(&display_hour);
+                            let slot: Vec<&Meeting> = all_meetings.iter()
+                                .filter(|m| m.utc_hour == utc_hour && zones.meeting_ring(m) == row)
+                                .collect();
+
+                            let mut label = format!(
+                                "{} {}, {}",
+                                tf.time(display_hour), l.zone_name(tz),
+                                l.t(if is_working { Msg::WorkingHours } else { Msg::OutsideHours })
+                            );
+                            if !slot.is_empty() {
+                                label.push_str(&format!(", {}", l.count(Count::Meetings, slot.len())));
+                            }
+                            let tooltip = slot.iter().map(|m| {
+                                if m.essential {
+                                    format!("\u{2022} {} {}", m.title, l.t(Msg::EssentialTag))
+                                } else {
+                                    format!("\u{2022} {}", m.title)
+                                }
+                            }).collect::<Vec<_>>().join("\n");
+                            let fill = if is_working { t.working_hours.to_string() } else { t.ring_fill(zones.palette_index(row)) };
+                            let stripes = if striped && is_working {
+                                format!("background-image: repeating-linear-gradient(45deg, {} 0 2px, transparent 2px 6px);", t.working_hours_hatch)
+                            } else {
+                                String::new()
+                            };
+                            let markers = slot.iter().map(|m| view! {
+                                <span style=format!(
+                                    "width: 6px; height: 6px; border-radius: 50%; border: 1px solid {}; background: {}",
+                                    t.background,
+                                    if m.essential { t.meeting_essential } else { t.meeting_non_essential }
+                                ) />
+                            }).collect_view();
+
+                            view! {
+                                <button
+                                    aria-label=label
+                                    title=(!tooltip.is_empty()).then_some(tooltip)
+                                    data-row=row
+                                    data-column=column
+                                    tabindex=move || {
+                                        // Rows removed since the last move fall back to the last one
+                                        let (focus_row, focus_column) = focus.get();
+                                        if (focus_row.min(rows - 1), focus_column) == (row, column) { "0" } else { "-1" }
+                                    }
+                                    style=move || {
+                                        let selected = selected_slot.with(|s| s.as_ref().is_some_and(|s| s.utc_hour == utc_hour));
+                                        format!(
+                                            "display: flex; flex-direction: column; align-items: center; justify-content: center; gap: 2px; min-height: 36px; padding: 2px 0; border: none; border-right: 1px solid {}; font-size: 10px; cursor: pointer; color: {}; background-color: {}; {} outline: {}; outline-offset: -2px",
+                                            t.segment_stroke, t.text_primary, fill, stripes,
+                                            if selected { format!("2px solid {}", t.text_primary) } else { "none".to_string() }
+                                        )
+                                    }
+                                    on:focus=move |_| focus.set((row, column))
+                                    on:click=move |_| {
+                                        set_selected_slot.set(Some(active_zones.with_untracked(|z| SelectedSlot::at(utc_hour, z))));
+                                    }
+                                >
+                                    <span aria-hidden="true">{tf.compact(display_hour)}</span>
+                                    <span aria-hidden="true" style="display: flex; gap: 2px; min-height: 6px">{markers}</span>
+                                </button>
+                            }
+                        }).collect_view();
+
+                        view! {
+                            <div style="display: flex">
+                                <div style=format!(
+                                    "width: {}; flex-shrink: 0; display: flex; align-items: center; gap: 4px; padding: 0 6px; font-weight: 600; overflow: hidden; white-space: nowrap; text-overflow: ellipsis; color: {}",
+                                    LABEL_WIDTH, t.ring_text(zones.palette_index(row))
+                                )>
+                                    {l.zone_name(tz).to_string()}
+                                    {is_here.then(|| view! {
+                                        <span
+                                            title=l.t(Msg::YouAreHere)
+                                            aria-label=l.t(Msg::YouAreHere)
+                                            style=format!("width: 8px; height: 8px; flex-shrink: 0; border-radius: 50%; background: {}", t.now_highlight)
+                                        />
+                                    })}
+                                </div>
+                                <div style=format!(
+                                    "flex: 1; position: relative; display: grid; grid-template-columns: repeat(24, minmax(0, 1fr)); border: 1px solid {}; {}",
+                                    t.segment_stroke,
+                                    if row + 1 < rows { "border-bottom: none" } else { "" }
+                                )>
+                                    {cells}
+                                    {now_line()}
+                                </div>
+                            </div>
+                        }
+                    }).collect_view()
+                }}
+            </div>
+        </div>
+    }
+}
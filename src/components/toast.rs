@@ -0,0 +1,68 @@
+use leptos::prelude::*;
+use gloo_timers::callback::Timeout;
+use crate::modules::*;
+
+const TOAST_MS: u32 = 6000;
+
+/// Bottom-of-screen notice after a destructive edit, with an "Undo" action.
+/// Hides itself after a few seconds.
+#[component]
+pub fn UndoToast(
+    message: ReadSignal<Option<Removal>>,
+    set_message: WriteSignal<Option<Removal>>,
+    on_undo: Callback<()>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    // Each new message restarts the countdown; stale timers see a newer generation and do nothing
+    let generation = StoredValue::new(0u32);
+    Effect::new(move || {
+        if message.get().is_none() {
+            return;
+        }
+        generation.update_value(|g| *g += 1);
+        let current = generation.get_value();
+        Timeout::new(TOAST_MS, move || {
+            if generation.get_value() == current {
+                set_message.set(None);
+            }
+        }).forget();
+    });
+
+    view! {
+        {move || {
+            let l = lang.get();
+            let text = message.with(|m| m.as_ref().map(|r| l.removal(r)))?;
+            let t = THEME_VARS;
+            Some(view! {
+                <div
+                    role="status"
+                    style=format!(
+                        "position: fixed; left: 50%; bottom: 24px; transform: translateX(-50%); display: flex; align-items: center; gap: 16px; padding: 10px 16px; border-radius: 8px; font-size: 0.8rem; box-shadow: 0 4px 12px rgb(0 0 0 / 0.2); z-index: 10; background: {}; border: 1px solid {}; color: {}",
+                        t.card_bg, t.card_border, t.text_primary
+                    )
+                >
+                    <span>{text}</span>
+                    <button
+                        style=format!(
+                            "background: none; border: none; cursor: pointer; font-weight: 600; font-size: 0.8rem; color: {}",
+                            t.button_primary_bg
+                        )
+                        on:click=move |_| {
+                            on_undo.run(());
+                            set_message.set(None);
+                        }
+                    >
+                        {l.t(Msg::Undo)}
+                    </button>
+                    <button
+                        style=format!("background: none; border: none; cursor: pointer; font-size: 0.8rem; color: {}", t.text_muted)
+                        aria-label=l.t(Msg::Dismiss)
+                        on:click=move |_| set_message.set(None)
+                    >
+                        "\u{2715}"
+                    </button>
+                </div>
+            })
+        }}
+    }
+}
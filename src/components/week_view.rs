@@ -0,0 +1,319 @@
+use leptos::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::modules::*;
+
+/// Width of the day column
+const LABEL_WIDTH: &str = "112px";
+
+/// Seven days of the reference zone as rows of its 24 wall-clock hours, each
+/// cell shaded by how many zones are working then. Unlike the dial it follows
+/// daylight saving time and weekends, so the overlap can differ from day to
+/// day; hours skipped or repeated by a clock change are marked.
+#[component]
+pub fn WeekView(
+    meetings: ReadSignal<Vec<Meeting>>,
+    selected_slot: ReadSignal<Option<SelectedSlot>>,
+    set_selected_slot: WriteSignal<Option<SelectedSlot>>,
+    current_utc: ReadSignal<f64>,
+    active_zones: ReadSignal<ActiveTimezones>,
+    reference: Signal<Reference>,
+    /// The zone behind the reference, whose daylight saving time the grid follows
+    reference_tz: Memo<Option<&'static TimezoneEntry>>,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    let t = THEME_VARS;
+    // Weeks away from the current one
+    let (week_offset, set_week_offset) = signal(0i64);
+    // (day, column) of the one cell reachable with Tab; arrow keys move it
+    let focus = RwSignal::new((0usize, 0u32));
+
+    let ref_offset_at = move |instant: f64| match reference_tz.get() {
+        Some(tz) => offset_at(tz, instant),
+        None => reference.get().utc_offset,
+    };
+    let now = Memo::new(move |_| {
+        current_utc.track();
+        now_epoch_hours()
+    });
+    let first_day = Memo::new(move |_| {
+        let now = now.get();
+        let today = (now + ref_offset_at(now)).div_euclid(24.0) as i64;
+        week_start(today) + 7 * week_offset.get()
+    });
+    let day_start = move |day: i64| match reference_tz.get() {
+        Some(tz) => local_day_start(Some(tz), day),
+        None => day as f64 * 24.0 - reference.get().utc_offset,
+    };
+
+    let range = move || {
+        let locale = lang.get().code();
+        let first = first_day.get();
+        format!("{} \u{2013} {}", format_day(locale, first), format_day(locale, first + 6))
+    };
+
+    // Zones changing their clocks within the shown week
+    let clock_changes = move || {
+        let first = first_day.get();
+        let (from, to) = (day_start(first), day_start(first + 7));
+        let l = lang.get();
+        let mut zones: Vec<&'static TimezoneEntry> = active_zones.with(|z| z.zones().iter().filter_map(|tz| zone_by_id(tz.id)).collect());
+        if let Some(tz) = reference_tz.get().filter(|r| zones.iter().all(|tz| tz.id != r.id)) {
+            zones.push(tz);
+        }
+        let changes = zones.iter()
+            .flat_map(|tz| offset_changes(tz, from, to).into_iter().map(move |c| (tz, c)))
+            .map(|(tz, c)| {
+                let local_day = (c.at + c.to).div_euclid(24.0) as i64;
+                format!("{} {} ({})", l.zone_name(tz), l.shift(c.to - c.from), format_day(l.code(), local_day))
+            })
+            .collect::<Vec<_>>();
+        (!changes.is_empty()).then(|| l.fill(Msg::ClocksChangeThisWeek, &[&changes.join(", ")]))
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let Some((day, column)) = dial_neighbor(focus.get_untracked(), &ev.key(), 7) else { return };
+        ev.prevent_default();
+        focus.set((day, column));
+        let target = ev.current_target()
+            .and_then(|t| t.dyn_into::<web_sys::Element>().ok())
+            .and_then(|el| el.query_selector(&format!("[data-day=\"{}\"][data-column=\"{}\"]", day, column)).ok().flatten())
+            .and_then(|el| el.dyn_into::<web_sys::HtmlElement>().ok());
+        if let Some(el) = target {
+            let _ = el.focus();
+        }
+    };
+
+    let nav_button = move |msg: Msg, label: &'static str, step: i64| view! {
+        <button
+            title=move || lang.get().t(msg)
+            aria-label=move || lang.get().t(msg)
+            style=format!(
+                "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 2px 10px; font-size: 12px; cursor: pointer",
+                t.button_secondary_bg, t.text_secondary, t.card_border
+            )
+            on:click=move |_| set_week_offset.update(|w| *w += step)
+        >
+            {label}
+        </button>
+    };
+
+    view! {
+        <div style="width: 100%; display: flex; flex-direction: column; gap: 8px">
+            <div style="display: flex; align-items: center; justify-content: space-between; gap: 8px; flex-wrap: wrap">
+                <span style=format!("font-size: 13px; font-weight: 600; color: {}", t.text_primary)>{range}</span>
+                <div style="display: flex; gap: 4px">
+                    {nav_button(Msg::PreviousWeek, "\u{2039}", -1)}
+                    <button
+                        disabled=move || week_offset.get() == 0
+                        style=move || format!(
+                            "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 2px 10px; font-size: 12px; cursor: pointer; opacity: {}",
+                            t.button_secondary_bg, t.text_secondary, t.card_border,
+                            if week_offset.get() == 0 { 0.4 } else { 1.0 }
+                        )
+                        on:click=move |_| set_week_offset.set(0)
+                    >
+                        {move || lang.get().t(Msg::ThisWeek)}
+                    </button>
+                    {nav_button(Msg::NextWeek, "\u{203A}", 1)}
+                </div>
+            </div>
+
+            <div
+                role="group"
+                aria-label=move || lang.get().t(Msg::WeekLabel)
+                style="width: 100%; overflow-x: auto"
+                on:keydown=on_keydown
+            >
+                <div style="min-width: 720px; display: flex; flex-direction: column; font-size: 10px">
+                    // Reference-zone hours
+                    <div style="display: flex" aria-hidden="true">
+                        <div style=format!("width: {}; flex-shrink: 0; padding: 2px 6px; font-weight: 600; color: {}", LABEL_WIDTH, t.text_secondary)>
+                            {move || reference.get().short_name}
+                        </div>
+                        <div style="flex: 1; display: grid; grid-template-columns: repeat(24, minmax(0, 1fr))">
+                            {move || {
+                                let tf = time_format.get();
+                                (0u32..24).map(|column| view! {
+                                    <div style=format!("text-align: center; padding: 2px 0; color: {}", t.text_muted)>
+                                        {tf.compact(column as f64)}
+                                    </div>
+                                }).collect_view()
+                            }}
+                        </div>
+                    </div>
+
+                    {(0..7usize).map(|row| {
+                        let day = Memo::new(move |_| first_day.get() + row as i64);
+                        // Each label builds an `Intl.DateTimeFormat`, so once per row
+                        let day_label = Memo::new(move |_| format_day(lang.get().code(), day.get()));
+                        let hours = Memo::new(move |_| {
+                            let day = day.get();
+                            match reference_tz.get() {
+                                Some(tz) => wall_hours(tz, day),
+                                None => (0..24).map(|h| WallHour::Once(day_start(day) + h as f64)).collect(),
+                            }
+                        });
+                        view! {
+                            <div style="display: flex">
+                                <div style=move || format!(
+                                    "width: {}; flex-shrink: 0; display: flex; align-items: center; padding: 0 6px; font-weight: 600; white-space: nowrap; color: {}",
+                                    LABEL_WIDTH, if weekday(day.get()) >= SATURDAY { t.text_muted } else { t.text_primary }
+                                )>
+                                    {day_label}
+                                </div>
+                                <div style=format!(
+                                    "flex: 1; display: grid; grid-template-columns: repeat(24, minmax(0, 1fr)); border: 1px solid {}; {}",
+                                    t.segment_stroke,
+                                    if row < 6 { "border-bottom: none" } else { "" }
+                                )>
+                                    {(0u32..24).map(|column| view! {
+                                        <WeekCell
+                                            row=row
+                                            column=column
+                                            wall=Memo::new(move |_| hours.with(|h| h[column as usize]))
+                                            day_label=day_label
+                                            now=now
+                                            meetings=meetings
+                                            active_zones=active_zones
+                                            selected_slot=selected_slot
+                                            set_selected_slot=set_selected_slot
+                                            focus=focus
+                                            time_format=time_format
+                                            lang=lang
+                                        />
+                                    }).collect_view()}
+                                </div>
+                            </div>
+                        }
+                    }).collect_view()}
+                </div>
+            </div>
+
+            {move || clock_changes().map(|text| view! {
+                <p style=format!(
+                    "font-size: 12px; padding: 6px 10px; border-radius: 6px; background: {}; border: 1px solid {}; color: {}",
+                    t.warning_bg, t.warning_border, t.warning_text
+                )>
+                    {text}
+                </p>
+            })}
+        </div>
+    }
+}
+
+/// One wall-clock hour of the week grid. Each piece of derived state is its
+/// own memo, so the minute tick or a meeting edit only touches the cells and
+/// attributes that depend on it.
+#[component]
+fn WeekCell(
+    row: usize,
+    column: u32,
+    wall: Memo<WallHour>,
+    day_label: Memo<String>,
+    now: Memo<f64>,
+    meetings: ReadSignal<Vec<Meeting>>,
+    active_zones: ReadSignal<ActiveTimezones>,
+    selected_slot: ReadSignal<Option<SelectedSlot>>,
+    set_selected_slot: WriteSignal<Option<SelectedSlot>>,
+    focus: RwSignal<(usize, u32)>,
+    time_format: Signal<TimeFormat>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    let t = THEME_VARS;
+    let instants = Memo::new(move |_| wall.get().instants());
+    let total = Memo::new(move |_| active_zones.with(|z| z.zones().len()));
+    let working = Memo::new(move |_| {
+        instants.with(|i| i.first().map_or(0, |&i| active_zones.with(|z| working_count_at(z.zones(), i))))
+    });
+    let utc_hours = Memo::new(move |_| {
+        instants.with(|i| i.iter().map(|i| i.rem_euclid(24.0).round() as u32 % 24).collect::<Vec<_>>())
+    });
+    let is_now = Memo::new(move |_| {
+        let now = now.get();
+        instants.with(|i| i.iter().any(|&i| (i..i + 1.0).contains(&now)))
+    });
+    // Meetings in this hour: (title, essential)
+    let slot = Memo::new(move |_| {
+        utc_hours.with(|hours| meetings.with(|ms| {
+            ms.iter()
+                .filter(|m| hours.contains(&m.utc_hour))
+                .map(|m| (m.title.clone(), m.essential))
+                .collect::<Vec<_>>()
+        }))
+    });
+
+    let label = Memo::new(move |_| {
+        let l = lang.get();
+        let mut label = format!("{} {}", day_label.get(), time_format.with(|f| f.time(column as f64)));
+        match wall.get() {
+            WallHour::Skipped => label.push_str(&format!(", {}", l.t(Msg::SkippedHour))),
+            WallHour::Twice(..) => label.push_str(&format!(", {}", l.t(Msg::RepeatedHour))),
+            WallHour::Once(_) => {}
+        }
+        if instants.with(|i| !i.is_empty()) {
+            label.push_str(&format!(", {}", l.fill(Msg::WeekCell, &[&working.get().to_string(), &total.get().to_string()])));
+        }
+        let n = slot.with(Vec::len);
+        if n > 0 {
+            label.push_str(&format!(", {}", l.count(Count::Meetings, n)));
+        }
+        label
+    });
+    let tooltip = move || {
+        slot.with(|s| (!s.is_empty()).then(|| s.iter().map(|(title, _)| format!("\u{2022} {}", title)).collect::<Vec<_>>().join("\n")))
+    };
+    let fill = Memo::new(move |_| {
+        let (working, total) = (working.get(), total.get());
+        if wall.get() == WallHour::Skipped {
+            format!("repeating-linear-gradient(45deg, {} 0 1px, {} 1px 5px)", t.segment_stroke, t.card_bg)
+        } else if working == total {
+            t.working_hours.to_string()
+        } else if working == 0 {
+            t.card_bg.to_string()
+        } else {
+            format!("color-mix(in srgb, {} {}%, {})", t.working_hours, 15 + 50 * working / total, t.card_bg)
+        }
+    });
+    let markers = move || slot.get().into_iter().map(|(_, essential)| view! {
+        <span style=format!(
+            "width: 6px; height: 6px; border-radius: 50%; border: 1px solid {}; background: {}",
+            t.background,
+            if essential { t.meeting_essential } else { t.meeting_non_essential }
+        ) />
+    }).collect_view();
+
+    view! {
+        <button
+            aria-label=label
+            title=tooltip
+            data-day=row
+            data-column=column
+            tabindex=move || if focus.get() == (row, column) { "0" } else { "-1" }
+            style=move || {
+                let selected = selected_slot.with(|s| s.as_ref().is_some_and(|s| utc_hours.with(|h| h.contains(&s.utc_hour))));
+                format!(
+                    "display: flex; flex-direction: column; align-items: center; justify-content: center; gap: 2px; min-height: 32px; padding: 2px 0; border: none; border-right: 1px solid {}; font-size: 9px; cursor: pointer; color: {}; background: {}; box-shadow: {}; outline: {}; outline-offset: -2px",
+                    t.segment_stroke, t.text_primary, fill.get(),
+                    if is_now.get() { format!("inset 0 0 0 2px {}", t.now_line) } else { "none".to_string() },
+                    if selected { format!("2px solid {}", t.text_primary) } else { "none".to_string() }
+                )
+            }
+            on:focus=move |_| focus.set((row, column))
+            on:click=move |_| {
+                if let Some(utc_hour) = utc_hours.with_untracked(|h| h.first().copied()) {
+                    set_selected_slot.set(Some(active_zones.with_untracked(|z| SelectedSlot::at(utc_hour, z))));
+                }
+            }
+        >
+            <span aria-hidden="true">
+                {move || {
+                    let working = working.get();
+                    (working > 0).then(|| format!("{}/{}", working, total.get()))
+                }}
+                {move || matches!(wall.get(), WallHour::Twice(..)).then_some(" \u{00D7}2")}
+            </span>
+            <span aria-hidden="true" style="display: flex; gap: 2px; min-height: 6px">{markers}</span>
+        </button>
+    }
+}
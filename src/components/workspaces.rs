@@ -0,0 +1,155 @@
+use leptos::prelude::*;
+use crate::modules::*;
+
+/// Header control for picking, creating, duplicating, renaming and deleting workspaces.
+/// The app loads a workspace's state whenever `active_workspace` changes.
+#[component]
+pub fn WorkspaceSwitcher(
+    workspaces: ReadSignal<Vec<Workspace>>,
+    set_workspaces: WriteSignal<Vec<Workspace>>,
+    active_workspace: ReadSignal<u32>,
+    set_active_workspace: WriteSignal<u32>,
+    lang: Signal<Language>,
+) -> impl IntoView {
+    let (renaming, set_renaming) = signal(false);
+    let (rename_text, set_rename_text) = signal(String::new());
+
+    let commit_rename = move || {
+        let name = rename_text.get_untracked().trim().to_string();
+        if !name.is_empty() {
+            let id = active_workspace.get_untracked();
+            set_workspaces.update(|ws| {
+                if let Some(w) = ws.iter_mut().find(|w| w.id == id) {
+                    w.name = name;
+                }
+            });
+        }
+        set_renaming.set(false);
+    };
+
+    let create = move |_| {
+        let ws = workspaces.get_untracked();
+        let mut workspace = Workspace::new(next_workspace_id(&ws), unique_workspace_name(&ws, lang.get_untracked().t(Msg::Workspace)));
+        if let Some(current) = ws.iter().find(|w| w.id == active_workspace.get_untracked()) {
+            workspace.theme = current.theme;
+        }
+        let id = workspace.id;
+        set_workspaces.update(|ws| ws.push(workspace));
+        set_active_workspace.set(id);
+    };
+
+    let duplicate = move |_| {
+        let ws = workspaces.get_untracked();
+        let Some(current) = ws.iter().find(|w| w.id == active_workspace.get_untracked()) else { return };
+        let copy = Workspace {
+            id: next_workspace_id(&ws),
+            name: unique_workspace_name(&ws, &lang.get_untracked().fill(Msg::CopyOf, &[&current.name])),
+            ..current.clone()
+        };
+        let id = copy.id;
+        set_workspaces.update(|ws| ws.push(copy));
+        set_active_workspace.set(id);
+    };
+
+    let delete = move |_| {
+        let ws = workspaces.get_untracked();
+        let id = active_workspace.get_untracked();
+        if ws.len() <= 1 {
+            return;
+        }
+        let name = ws.iter().find(|w| w.id == id).map(|w| w.name.clone()).unwrap_or_default();
+        let confirmed = web_sys::window()
+            .and_then(|w| w.confirm_with_message(&lang.get_untracked().fill(Msg::ConfirmDeleteWorkspace, &[&name])).ok())
+            .unwrap_or(false);
+        if !confirmed {
+            return;
+        }
+        let next = ws.iter().find(|w| w.id != id).map(|w| w.id).unwrap_or(id);
+        set_workspaces.update(|ws| ws.retain(|w| w.id != id));
+        set_active_workspace.set(next);
+    };
+
+    view! {
+        {move || {
+            let t = THEME_VARS;
+            let l = lang.get();
+            let ws = workspaces.get();
+            let active = active_workspace.get();
+            let can_delete = ws.len() > 1;
+
+            let control_style = format!(
+                "border: 1px solid {}; background: {}; color: {}; border-radius: 6px; padding: 4px 6px; font-size: 12px",
+                t.input_border, t.input_bg, t.text_primary
+            );
+            let icon_style = format!(
+                "background: {}; color: {}; border: 1px solid {}; border-radius: 6px; padding: 2px 8px; font-size: 12px; cursor: pointer",
+                t.button_secondary_bg, t.text_secondary, t.card_border
+            );
+
+            let picker = if renaming.get() {
+                view! {
+                    <input
+                        type="text"
+                        style=control_style.clone()
+                        prop:value=move || rename_text.get()
+                        on:input=move |ev| set_rename_text.set(event_target_value(&ev))
+                        on:keydown=move |ev| match ev.key().as_str() {
+                            "Enter" => commit_rename(),
+                            "Escape" => set_renaming.set(false),
+                            _ => {}
+                        }
+                        on:blur=move |_| commit_rename()
+                    />
+                }.into_any()
+            } else {
+                view! {
+                    <select
+                        aria-label=l.t(Msg::Workspace)
+                        style=format!("{}; cursor: pointer", control_style)
+                        prop:value=active.to_string()
+                        on:change=move |ev| {
+                            if let Ok(id) = event_target_value(&ev).parse::<u32>() {
+                                set_active_workspace.set(id);
+                            }
+                        }
+                    >
+                        {ws.iter().map(|w| {
+                            let id = w.id;
+                            view! {
+                                <option value=id.to_string() selected=id == active>{w.name.clone()}</option>
+                            }
+                        }).collect_view()}
+                    </select>
+                }.into_any()
+            };
+
+            let current_name = ws.iter().find(|w| w.id == active).map(|w| w.name.clone()).unwrap_or_default();
+
+            view! {
+                <div style="display: flex; align-items: center; gap: 4px">
+                    {picker}
+                    <button style=icon_style.clone() title=l.t(Msg::NewWorkspace) on:click=create>"+"</button>
+                    <button style=icon_style.clone() title=l.t(Msg::DuplicateWorkspace) on:click=duplicate>"\u{29C9}"</button>
+                    <button
+                        style=icon_style.clone()
+                        title=l.t(Msg::RenameWorkspace)
+                        on:click=move |_| {
+                            set_rename_text.set(current_name.clone());
+                            set_renaming.set(true);
+                        }
+                    >
+                        "\u{270E}"
+                    </button>
+                    <button
+                        style=format!("{}; opacity: {}", icon_style, if can_delete { 1.0 } else { 0.4 })
+                        title=l.t(Msg::DeleteWorkspace)
+                        disabled=!can_delete
+                        on:click=delete
+                    >
+                        "\u{2715}"
+                    </button>
+                </div>
+            }
+        }}
+    }
+}
@@ -1,8 +1,23 @@
 mod app;
 mod components;
 mod modules;
+mod web_component;
+
+use leptos::prelude::*;
 
 fn main() {
     console_error_panic_hook::set_once();
-    leptos::mount::mount_to_body(app::App);
+    web_component::register();
+    if web_component::elements_only() {
+        return;
+    }
+    // Embeds never touch storage, so only the full app waits for a store
+    if let Some(config) = app::embed_config() {
+        leptos::mount::mount_to_body(move || view! { <app::EmbedApp config=config /> });
+        return;
+    }
+    leptos::task::spawn_local(async {
+        let opened = modules::open_store().await;
+        leptos::mount::mount_to_body(move || view! { <app::App opened=opened /> });
+    });
 }
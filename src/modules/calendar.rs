@@ -0,0 +1,535 @@
+//! Calendar dates, daylight saving time and weekends, for views that span
+//! more than one day. Instants are "epoch hours": hours since
+//! 1970-01-01 00:00 UTC. Days are counted from the same epoch.
+
+use crate::modules::timezone::utc_to_local;
+use crate::modules::types::{Meeting, TimezoneEntry};
+
+/// Days since 1970-01-01 of a proleptic Gregorian date
+pub fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year } as i64;
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Inverse of [`days_from_civil`]: (year, month, day)
+pub fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+    (year, month, day)
+}
+
+/// Day of the week, 0 = Monday ... 6 = Sunday
+pub fn weekday(days: i64) -> u32 {
+    // 1970-01-01 was a Thursday
+    (days + 3).rem_euclid(7) as u32
+}
+
+pub const SATURDAY: u32 = 5;
+pub const SUNDAY: u32 = 6;
+
+#[derive(Debug, Clone, Copy)]
+enum Week {
+    Nth(i64),
+    Last,
+}
+
+/// When clocks change: a weekday of a month, at an hour of local standard
+/// time, or of UTC for the EU's simultaneous switch
+#[derive(Debug, Clone, Copy)]
+struct Transition {
+    month: u32,
+    weekday: u32,
+    week: Week,
+    hour: f64,
+    utc: bool,
+}
+
+impl Transition {
+    fn at(&self, year: i32, standard_offset: f64) -> f64 {
+        let first = days_from_civil(year, self.month, 1);
+        let day = match self.week {
+            Week::Nth(n) => first + (self.weekday as i64 - weekday(first) as i64).rem_euclid(7) + 7 * (n - 1),
+            Week::Last => {
+                let (next_year, next_month) = if self.month == 12 { (year + 1, 1) } else { (year, self.month + 1) };
+                let last = days_from_civil(next_year, next_month, 1) - 1;
+                last - (weekday(last) as i64 - self.weekday as i64).rem_euclid(7)
+            }
+        };
+        day as f64 * 24.0 + self.hour - if self.utc { 0.0 } else { standard_offset }
+    }
+}
+
+/// Daylight saving time: `shift` hours ahead of standard time from `start` until `end`
+#[derive(Debug, Clone, Copy)]
+pub struct DstRule {
+    start: Transition,
+    end: Transition,
+    shift: f64,
+}
+
+const fn local(month: u32, weekday: u32, week: Week, hour: f64) -> Transition {
+    Transition { month, weekday, week, hour, utc: false }
+}
+
+const US: DstRule = DstRule {
+    start: local(3, SUNDAY, Week::Nth(2), 2.0),
+    end: local(11, SUNDAY, Week::Nth(1), 1.0),
+    shift: 1.0,
+};
+
+const EU: DstRule = DstRule {
+    start: Transition { month: 3, weekday: SUNDAY, week: Week::Last, hour: 1.0, utc: true },
+    end: Transition { month: 10, weekday: SUNDAY, week: Week::Last, hour: 1.0, utc: true },
+    shift: 1.0,
+};
+
+/// Rules in force for the zones of the built-in database, by IANA name.
+/// End hours are given in standard time, i.e. one shift before the wall clock.
+static DST_RULES: &[(&str, DstRule)] = &[
+    ("America/Anchorage", US),
+    ("America/Los_Angeles", US),
+    ("America/Denver", US),
+    ("America/Chicago", US),
+    ("America/New_York", US),
+    ("America/St_Johns", US),
+    ("Atlantic/Azores", EU),
+    ("Europe/London", EU),
+    ("Europe/Paris", EU),
+    ("Europe/Berlin", EU),
+    ("Africa/Cairo", DstRule {
+        start: local(4, 4, Week::Last, 0.0),
+        end: local(10, 3, Week::Last, 23.0),
+        shift: 1.0,
+    }),
+    ("Australia/Sydney", DstRule {
+        start: local(10, SUNDAY, Week::Nth(1), 2.0),
+        end: local(4, SUNDAY, Week::Nth(1), 2.0),
+        shift: 1.0,
+    }),
+    ("Australia/Lord_Howe", DstRule {
+        start: local(10, SUNDAY, Week::Nth(1), 2.0),
+        end: local(4, SUNDAY, Week::Nth(1), 1.5),
+        shift: 0.5,
+    }),
+    ("Pacific/Auckland", DstRule {
+        start: local(9, SUNDAY, Week::Last, 2.0),
+        end: local(4, SUNDAY, Week::Nth(1), 2.0),
+        shift: 1.0,
+    }),
+    ("Pacific/Chatham", DstRule {
+        start: local(9, SUNDAY, Week::Last, 2.75),
+        end: local(4, SUNDAY, Week::Nth(1), 2.75),
+        shift: 1.0,
+    }),
+];
+
+pub fn dst_rule(tz: &TimezoneEntry) -> Option<&'static DstRule> {
+    DST_RULES.iter().find(|(iana, _)| *iana == tz.iana).map(|(_, rule)| rule)
+}
+
+/// The zone's UTC offset at an instant, including daylight saving time
+pub fn offset_at(tz: &TimezoneEntry, instant: f64) -> f64 {
+    let Some(rule) = dst_rule(tz) else { return tz.utc_offset };
+    let (year, ..) = civil_from_days((instant + tz.utc_offset).div_euclid(24.0) as i64);
+    let start = rule.start.at(year, tz.utc_offset);
+    let end = rule.end.at(year, tz.utc_offset);
+    let in_dst = if start < end {
+        (start..end).contains(&instant)
+    } else {
+        // Southern hemisphere: daylight time spans the new year
+        instant >= start || instant < end
+    };
+    if in_dst { tz.utc_offset + rule.shift } else { tz.utc_offset }
+}
+
+/// A change of a zone's UTC offset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OffsetChange {
+    pub at: f64,
+    pub from: f64,
+    pub to: f64,
+}
+
+/// Offset changes in `[from, to)`, in order
+pub fn offset_changes(tz: &TimezoneEntry, from: f64, to: f64) -> Vec<OffsetChange> {
+    let Some(rule) = dst_rule(tz) else { return Vec::new() };
+    let first_year = civil_from_days((from / 24.0).floor() as i64).0 - 1;
+    let last_year = civil_from_days((to / 24.0).floor() as i64).0 + 1;
+    let mut changes: Vec<OffsetChange> = (first_year..=last_year)
+        .flat_map(|year| {
+            let std = tz.utc_offset;
+            [
+                OffsetChange { at: rule.start.at(year, std), from: std, to: std + rule.shift },
+                OffsetChange { at: rule.end.at(year, std), from: std + rule.shift, to: std },
+            ]
+        })
+        .filter(|c| (from..to).contains(&c.at))
+        .collect();
+    changes.sort_by(|a, b| a.at.total_cmp(&b.at));
+    changes
+}
+
+/// Weekends other than Saturday-Sunday, by zone id
+static WEEKENDS: &[(&str, &[u32])] = &[
+    ("africa_cairo", &[4, SATURDAY]),
+    ("asia_tehran", &[4]),
+];
+
+pub fn is_weekend(tz: &TimezoneEntry, day_of_week: u32) -> bool {
+    match WEEKENDS.iter().find(|(id, _)| *id == tz.id) {
+        Some((_, days)) => days.contains(&day_of_week),
+        None => day_of_week >= SATURDAY,
+    }
+}
+
+/// Whether the zone is inside its working hours on a working day at an instant
+pub fn is_working_at(tz: &TimezoneEntry, instant: f64) -> bool {
+    let local = instant + offset_at(tz, instant);
+    let day = local.div_euclid(24.0);
+    !is_weekend(tz, weekday(day as i64)) && tz.is_work_hour(local - day * 24.0)
+}
+
+/// How many of the zones are working at an instant; all of them is the dated
+/// form of [`is_full_overlap_utc`](crate::modules::timezone::is_full_overlap_utc)
+pub fn working_count_at(zones: &[TimezoneEntry], instant: f64) -> usize {
+    zones.iter().filter(|tz| is_working_at(tz, instant)).count()
+}
+
+/// The Monday starting the week that contains `day`
+pub fn week_start(day: i64) -> i64 {
+    day - weekday(day) as i64
+}
+
+/// Whether a daily meeting at `utc_hour` falls in everyone's working hours,
+/// with the offsets in force at `instant`
+pub fn meeting_in_overlap_at(utc_hour: u32, zones: &[TimezoneEntry], instant: f64) -> bool {
+    zones.iter().all(|tz| tz.is_work_hour(utc_to_local(utc_hour as f64, offset_at(tz, instant))))
+}
+
+/// How far ahead clock changes are announced. Europe and North America
+/// switch up to three weeks apart.
+pub const CLOCK_CHANGE_NOTICE_DAYS: f64 = 21.0;
+
+/// A meeting moved in local time by a clock change
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingShift {
+    pub meeting_id: u32,
+    /// Local time in the changing zone once the clocks have moved
+    pub local_after: f64,
+    /// The meeting's new overlap status, when the change flips it
+    pub overlap_after: Option<bool>,
+}
+
+/// An upcoming clock change in one of the active zones
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClockChangeWarning {
+    pub zone_id: &'static str,
+    pub change: OffsetChange,
+    /// Meetings organized in the zone, or whose overlap status changes
+    pub meetings: Vec<MeetingShift>,
+}
+
+/// Clock changes in the active zones from `now` to the notice horizon
+pub fn clock_change_warnings(zones: &[TimezoneEntry], meetings: &[Meeting], now: f64) -> Vec<ClockChangeWarning> {
+    let mut warnings: Vec<ClockChangeWarning> = zones.iter()
+        .flat_map(|tz| {
+            offset_changes(tz, now, now + CLOCK_CHANGE_NOTICE_DAYS * 24.0).into_iter().map(move |change| {
+                let shifts = meetings.iter().filter_map(|m| {
+                    let before = meeting_in_overlap_at(m.utc_hour, zones, change.at - 1e-6);
+                    let after = meeting_in_overlap_at(m.utc_hour, zones, change.at);
+                    (before != after || m.organizer_zone == tz.id).then(|| MeetingShift {
+                        meeting_id: m.id,
+                        local_after: utc_to_local(m.utc_hour as f64, change.to),
+                        overlap_after: (before != after).then_some(after),
+                    })
+                }).collect();
+                ClockChangeWarning { zone_id: tz.id, change, meetings: shifts }
+            })
+        })
+        .collect();
+    warnings.sort_by(|a, b| a.change.at.total_cmp(&b.change.at));
+    warnings
+}
+
+/// Fully overlapping working hours on one day of the reference zone
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DayOverlap {
+    pub day: i64,
+    pub hours: f64,
+}
+
+/// Samples per hour in [`year_overlap`]. Offsets such as Kathmandu's +5:45
+/// and clock changes fall on quarter hours, so working hours on the half or
+/// whole hour are counted exactly.
+const SAMPLES_PER_HOUR: f64 = 4.0;
+
+/// Full overlap for every day of `year`, counted in quarter hours. `day_start`
+/// gives the instant a reference-zone day begins, so days with a clock
+/// change are 23 or 25 hours long.
+pub fn year_overlap(zones: &[TimezoneEntry], year: i32, day_start: impl Fn(i64) -> f64) -> Vec<DayOverlap> {
+    (days_from_civil(year, 1, 1)..days_from_civil(year + 1, 1, 1))
+        .map(|day| {
+            let (start, end) = (day_start(day), day_start(day + 1));
+            let steps = ((end - start) * SAMPLES_PER_HOUR).round() as usize;
+            let full = (0..steps).filter(|&i| working_count_at(zones, start + i as f64 / SAMPLES_PER_HOUR) == zones.len()).count();
+            DayOverlap { day, hours: full as f64 / SAMPLES_PER_HOUR }
+        })
+        .collect()
+}
+
+/// Average overlap on the Monday-Friday days of each week, by week start
+pub fn weekly_overlap(days: &[DayOverlap]) -> Vec<(i64, f64)> {
+    let mut weeks: Vec<(i64, f64, usize)> = Vec::new();
+    for d in days.iter().filter(|d| weekday(d.day) < SATURDAY) {
+        let start = week_start(d.day);
+        match weeks.last_mut() {
+            Some((week, total, n)) if *week == start => {
+                *total += d.hours;
+                *n += 1;
+            }
+            _ => weeks.push((start, d.hours, 1)),
+        }
+    }
+    weeks.into_iter().map(|(week, total, n)| (week, total / n as f64)).collect()
+}
+
+/// Runs of consecutive weeks with the year's lowest weekday overlap, as
+/// (first day, last day). Empty when the overlap never changes.
+pub fn worst_periods(days: &[DayOverlap]) -> Vec<(i64, i64)> {
+    let weeks = weekly_overlap(days);
+    let min = weeks.iter().map(|w| w.1).fold(f64::INFINITY, f64::min);
+    let max = weeks.iter().map(|w| w.1).fold(f64::NEG_INFINITY, f64::max);
+    if weeks.is_empty() || max - min < 0.01 {
+        return Vec::new();
+    }
+    let (first, last) = (days[0].day, days[days.len() - 1].day);
+    let mut periods: Vec<(i64, i64)> = Vec::new();
+    for (week, _) in weeks.iter().filter(|w| w.1 - min < 0.01) {
+        let (start, end) = ((*week).max(first), (week + 6).min(last));
+        match periods.last_mut() {
+            Some((_, prev_end)) if *prev_end + 1 >= start => *prev_end = end,
+            _ => periods.push((start, end)),
+        }
+    }
+    periods
+}
+
+/// An hour of a local day on the wall clock
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WallHour {
+    /// Starts at this instant
+    Once(f64),
+    /// Jumped over as the clocks go forward
+    Skipped,
+    /// Lived through at both instants as the clocks go back
+    Twice(f64, f64),
+}
+
+impl WallHour {
+    pub fn instants(&self) -> Vec<f64> {
+        match *self {
+            WallHour::Once(a) => vec![a],
+            WallHour::Skipped => Vec::new(),
+            WallHour::Twice(a, b) => vec![a, b],
+        }
+    }
+}
+
+/// The 24 wall-clock hours of a local day in `tz`, following daylight saving time
+pub fn wall_hours(tz: &TimezoneEntry, day: i64) -> Vec<WallHour> {
+    let shift = dst_rule(tz).map_or(0.0, |r| r.shift);
+    // Larger offset first, so a repeated hour lists its earlier instant first
+    let mut offsets = vec![tz.utc_offset, tz.utc_offset + shift];
+    offsets.sort_by(|a, b| b.total_cmp(a));
+    offsets.dedup();
+    (0..24).map(|hour| {
+        let wall = (day * 24 + hour) as f64;
+        let starts: Vec<f64> = offsets.iter()
+            .filter(|&&offset| offset_at(tz, wall - offset) == offset)
+            .map(|offset| wall - offset)
+            .collect();
+        match starts[..] {
+            [] => WallHour::Skipped,
+            [a] => WallHour::Once(a),
+            [a, b, ..] => WallHour::Twice(a, b),
+        }
+    }).collect()
+}
+
+/// The instant a local day starts in `tz`, or in UTC when there is none
+pub fn local_day_start(tz: Option<&TimezoneEntry>, day: i64) -> f64 {
+    let midnight = day as f64 * 24.0;
+    match tz {
+        Some(tz) => midnight - offset_at(tz, midnight - tz.utc_offset),
+        None => midnight,
+    }
+}
+
+/// The current instant
+pub fn now_epoch_hours() -> f64 {
+    js_sys::Date::now() / 3_600_000.0
+}
+
+/// A day as `locale` writes it, e.g. "Mon, Mar 30"
+pub fn format_day(locale: &str, day: i64) -> String {
+    format_date(locale, day, &[("weekday", "short"), ("month", "short"), ("day", "numeric")])
+}
+
+/// The month a day falls in, abbreviated as `locale` writes it
+pub fn format_month(locale: &str, day: i64) -> String {
+    format_date(locale, day, &[("month", "short")])
+}
+
+fn format_date(locale: &str, day: i64, fields: &[(&str, &str)]) -> String {
+    let options = js_sys::Object::new();
+    for (key, value) in fields.iter().copied().chain([("timeZone", "UTC")]) {
+        let _ = js_sys::Reflect::set(&options, &key.into(), &value.into());
+    }
+    let dtf = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::of1(&locale.into()), &options);
+    let date = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64((day as f64 * 24.0 + 12.0) * 3_600_000.0));
+    dtf.format().call1(&dtf, &date).ok().and_then(|v| v.as_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::timezone_db::zone_by_id;
+
+    fn instant(year: i32, month: u32, day: u32, hour: f64) -> f64 {
+        days_from_civil(year, month, day) as f64 * 24.0 + hour
+    }
+
+    #[test]
+    fn test_civil_dates() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+        assert_eq!(weekday(days_from_civil(2025, 3, 30)), SUNDAY);
+        assert_eq!(week_start(days_from_civil(2025, 3, 30)), days_from_civil(2025, 3, 24));
+    }
+
+    #[test]
+    fn test_transitions() {
+        let dallas = zone_by_id("america_chicago").unwrap();
+        let london = zone_by_id("europe_london").unwrap();
+        let sydney = zone_by_id("australia_sydney").unwrap();
+
+        // US: 9 Mar 2025 08:00 UTC to 2 Nov 2025 07:00 UTC
+        let us = offset_changes(dallas, instant(2025, 1, 1, 0.0), instant(2026, 1, 1, 0.0));
+        assert_eq!(us, vec![
+            OffsetChange { at: instant(2025, 3, 9, 8.0), from: -6.0, to: -5.0 },
+            OffsetChange { at: instant(2025, 11, 2, 7.0), from: -5.0, to: -6.0 },
+        ]);
+        assert_eq!(offset_at(dallas, instant(2025, 7, 1, 12.0)), -5.0);
+
+        // EU: 30 Mar and 26 Oct 2025 at 01:00 UTC
+        assert_eq!(offset_at(london, instant(2025, 3, 30, 0.99)), 0.0);
+        assert_eq!(offset_at(london, instant(2025, 3, 30, 1.0)), 1.0);
+        assert_eq!(offset_at(london, instant(2025, 10, 26, 1.0)), 0.0);
+
+        // Sydney is on daylight time over the new year
+        assert_eq!(offset_at(sydney, instant(2025, 1, 15, 0.0)), 11.0);
+        assert_eq!(offset_at(sydney, instant(2025, 4, 5, 16.0)), 10.0);
+        assert_eq!(offset_at(sydney, instant(2025, 10, 4, 16.0)), 11.0);
+    }
+
+    #[test]
+    fn test_wall_hours() {
+        let london = zone_by_id("europe_london").unwrap();
+        let hour = |y, m, d, h| instant(y, m, d, h);
+
+        // 30 Mar 2025: 01:00 is skipped, 02:00 is 01:00 UTC
+        let spring = wall_hours(london, days_from_civil(2025, 3, 30));
+        assert_eq!(spring[0], WallHour::Once(hour(2025, 3, 30, 0.0)));
+        assert_eq!(spring[1], WallHour::Skipped);
+        assert_eq!(spring[2], WallHour::Once(hour(2025, 3, 30, 1.0)));
+        assert_eq!(spring[23], WallHour::Once(hour(2025, 3, 30, 22.0)));
+        assert_eq!(spring.iter().map(|h| h.instants().len()).sum::<usize>(), 23);
+
+        // 26 Oct 2025: 01:00 happens at 00:00 and again at 01:00 UTC
+        let autumn = wall_hours(london, days_from_civil(2025, 10, 26));
+        assert_eq!(autumn[0], WallHour::Once(hour(2025, 10, 25, 23.0)));
+        assert_eq!(autumn[1], WallHour::Twice(hour(2025, 10, 26, 0.0), hour(2025, 10, 26, 1.0)));
+        assert_eq!(autumn[2], WallHour::Once(hour(2025, 10, 26, 2.0)));
+        assert_eq!(autumn[23], WallHour::Once(hour(2025, 10, 26, 23.0)));
+        assert_eq!(autumn.iter().map(|h| h.instants().len()).sum::<usize>(), 25);
+
+        // Zones without daylight saving time just count hours from midnight
+        let tokyo = zone_by_id("asia_tokyo").unwrap();
+        assert_eq!(wall_hours(tokyo, days_from_civil(2025, 3, 30))[9], WallHour::Once(hour(2025, 3, 30, 0.0)));
+    }
+
+    #[test]
+    fn test_working_days() {
+        let london = zone_by_id("europe_london").unwrap();
+        let cairo = zone_by_id("africa_cairo").unwrap();
+        // Friday 3 Jan 2025, 10:00 UTC
+        let friday = instant(2025, 1, 3, 10.0);
+        assert!(is_working_at(london, friday));
+        assert!(!is_working_at(cairo, friday));
+        assert!(!is_working_at(london, friday + 24.0));
+        assert!(is_working_at(cairo, friday + 48.0));
+        assert_eq!(working_count_at(&[london.clone(), cairo.clone()], friday + 72.0), 2);
+    }
+
+    #[test]
+    fn test_clock_change_warnings() {
+        let zones = vec![zone_by_id("europe_london").unwrap().clone(), zone_by_id("america_chicago").unwrap().clone()];
+        let meeting = |id, utc_hour, organizer_zone| Meeting { id, utc_hour, title: String::new(), essential: false, organizer_zone };
+        let meetings = vec![meeting(1, 15, "europe_london"), meeting(2, 17, "america_chicago"), meeting(3, 3, "america_chicago")];
+
+        // Nine days before London springs forward; Chicago already has
+        let warnings = clock_change_warnings(&zones, &meetings, instant(2025, 3, 21, 1.0));
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].zone_id, "europe_london");
+        assert_eq!(warnings[0].change.at, instant(2025, 3, 30, 1.0));
+        assert_eq!(warnings[0].meetings, vec![
+            // 15:00 UTC becomes 16:00 in London, still inside the overlap
+            MeetingShift { meeting_id: 1, local_after: 16.0, overlap_after: None },
+            // 17:00 UTC becomes 18:00 in London, the end of its day
+            MeetingShift { meeting_id: 2, local_after: 18.0, overlap_after: Some(false) },
+        ]);
+
+        assert!(clock_change_warnings(&zones, &meetings, instant(2025, 6, 1, 0.0)).is_empty());
+    }
+
+    #[test]
+    fn test_year_overlap() {
+        let london = zone_by_id("europe_london").unwrap();
+        let new_york = zone_by_id("america_new_york").unwrap();
+        let zones = vec![london.clone(), new_york.clone()];
+        let days = year_overlap(&zones, 2025, |day| local_day_start(Some(london), day));
+        assert_eq!(days.len(), 365);
+        let on = |m, d| days.iter().find(|o| o.day == days_from_civil(2025, m, d)).unwrap().hours;
+        // 14:00-18:00 in London most of the year, 13:00-18:00 while only New York has moved
+        assert_eq!(on(1, 6), 4.0);
+        assert_eq!(on(3, 17), 5.0);
+        assert_eq!(on(3, 22), 0.0);
+
+        // The weeks between the US and EU switches stand out as the best, not the worst
+        let worst = worst_periods(&days);
+        assert!(!worst.is_empty());
+        assert!(worst.iter().all(|&(start, end)| !(start..=end).contains(&days_from_civil(2025, 3, 17))));
+        assert!(worst_periods(&year_overlap(std::slice::from_ref(london), 2025, |day| day as f64 * 24.0)).is_empty());
+    }
+
+    #[test]
+    fn test_year_overlap_quarter_hour_offset() {
+        // Kathmandu 09:00-18:00 is 03:15-12:15 UTC, Tokyo's is 00:00-09:00 UTC
+        let zones = vec![zone_by_id("asia_kathmandu").unwrap().clone(), zone_by_id("asia_tokyo").unwrap().clone()];
+        let days = year_overlap(&zones, 2025, |day| day as f64 * 24.0);
+        // Wednesday 1 January 2025
+        assert_eq!(days[0].hours, 5.75);
+    }
+}
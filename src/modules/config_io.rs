@@ -0,0 +1,597 @@
+//! Import/export of the whole app configuration as a JSON document:
+//!
+//! ```json
+//! {
+//!   "format": "tz-clock-config",
+//!   "version": 1,
+//!   "mode": "Dark",
+//!   "workspaces": [
+//!     {
+//!       "name": "Platform team",
+//!       "theme": "Bold",
+//!       "reference": "europe_london",
+//!       "zones": [
+//!         { "id": "america_chicago", "work_start": 9, "work_end": 18 },
+//!         { "id": "europe_london", "work_start": 8, "work_end": 16.5 }
+//!       ],
+//!       "meetings": [
+//!         { "id": 1, "title": "Sync", "utc_hour": 15, "essential": true, "organizer_zone": "europe_london" }
+//!       ]
+//!     }
+//!   ]
+//! }
+//! ```
+//!
+//! `theme` is a built-in theme name, or "custom-<id>" for a custom theme,
+//! which falls back to Minimalist in a browser that does not have it.
+//! Zones are referenced by the stable ids in `timezone_db.rs`. `reference`
+//! is the zone the dial is anchored to: "first" (the outer ring, default),
+//! "utc", "local" (the browser's zone) or one of the workspace's zone ids.
+//! `mode` is "Light", "Dark", "System" or "Sunset". `mode`, `theme`,
+//! `work_start`/`work_end` (local hours, default 9-18), per-zone `color`
+//! (the ring's palette slot, 0-11; the lowest free one if omitted), `meetings`,
+//! and per-meeting `id`, `essential` and `organizer_zone` are optional.
+
+use serde::Serialize;
+use serde_json::Value;
+use crate::modules::i18n::Language;
+use crate::modules::themes::{ModeSetting, ThemeName};
+use crate::modules::timezone_db::zone_by_id;
+use crate::modules::types::{ActiveTimezones, Meeting, ReferenceZone, TimezoneEntry, MAX_RINGS, WORK_START, WORK_END};
+use crate::modules::workspace::{next_workspace_id, MeetingRecord, Workspace, ZoneRecord};
+
+pub const CONFIG_FORMAT: &str = "tz-clock-config";
+pub const CONFIG_VERSION: u64 = 1;
+
+/// A validated configuration document
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigDocument {
+    pub mode: Option<ModeSetting>,
+    pub workspaces: Vec<Workspace>,
+}
+
+/// One problem found while validating an import, e.g.
+/// `workspaces[0].zones[1].id: unknown zone id "europe_lndon"`.
+/// Worded by [`Language::import_error`]; `Display` gives the English text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportError {
+    pub path: String,
+    pub problem: ImportProblem,
+}
+
+/// What is wrong at an [`ImportError`]'s path. `String` fields hold the
+/// offending value as written in the file.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ImportProblem {
+    InvalidJson(String),
+    NotAnObject,
+    MissingFormat(&'static str),
+    WrongFormat { expected: &'static str, got: String },
+    UnsupportedVersion { got: String, supported: u64 },
+    Missing,
+    Empty,
+    NoWorkspaces,
+    ExpectedString(String),
+    ExpectedArray(String),
+    ExpectedObject(String),
+    ExpectedBool(String),
+    /// A zone entry that is not an object
+    ExpectedZone(String),
+    UnknownMode(String),
+    UnknownTheme(String),
+    UnknownReference(String),
+    UnknownZone(String),
+    DuplicateZone(&'static str),
+    /// Number of zones found, outside 1 to `MAX_RINGS`
+    ZoneCount(usize),
+    NotInWorkspace(String),
+    HourOutOfRange { min: f64, max: f64, got: String },
+    EndNotAfterStart { start: f64, end: f64 },
+    /// A `color` that is not a palette slot from 0 to `MAX_RINGS - 1`
+    NotAColorSlot(String),
+    NotAUtcHour(String),
+    NotAnId(String),
+    DuplicateMeetingId(u32),
+    UnknownColorKey,
+    NotAColor(String),
+}
+
+impl std::fmt::Display for ImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&Language::En.import_error(self))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportMode {
+    /// Add imported workspaces; same-named workspaces gain the missing zones and meetings
+    Merge,
+    /// Discard all existing workspaces
+    Replace,
+}
+
+#[derive(Serialize)]
+struct ExportedWorkspace<'a> {
+    name: &'a str,
+    theme: String,
+    reference: &'static str,
+    zones: Vec<ZoneRecord>,
+    meetings: Vec<MeetingRecord>,
+}
+
+#[derive(Serialize)]
+struct ExportedConfig<'a> {
+    format: &'static str,
+    version: u64,
+    mode: &'static str,
+    workspaces: Vec<ExportedWorkspace<'a>>,
+}
+
+pub fn export_config(workspaces: &[Workspace], mode: ModeSetting) -> String {
+    let doc = ExportedConfig {
+        format: CONFIG_FORMAT,
+        version: CONFIG_VERSION,
+        mode: mode.label(),
+        workspaces: workspaces.iter().map(|w| ExportedWorkspace {
+            name: &w.name,
+            theme: w.theme.key(),
+            reference: w.reference.key(),
+            zones: ZoneRecord::from_zones(&w.zones),
+            meetings: w.meetings.iter().map(MeetingRecord::from).collect(),
+        }).collect(),
+    };
+    serde_json::to_string_pretty(&doc).unwrap_or_default()
+}
+
+/// Validate a document, collecting every problem rather than stopping at the first.
+/// Workspace ids in the result are placeholders; [`apply_import`] assigns real ones.
+pub fn parse_config(raw: &str) -> Result<ConfigDocument, Vec<ImportError>> {
+    let root: Value = serde_json::from_str(raw).map_err(|e| {
+        vec![ImportError { path: String::new(), problem: ImportProblem::InvalidJson(e.to_string()) }]
+    })?;
+    let mut errors = Vec::new();
+    let mut err = |path: String, problem: ImportProblem| errors.push(ImportError { path, problem });
+
+    let Some(obj) = root.as_object() else {
+        err(String::new(), ImportProblem::NotAnObject);
+        return Err(errors);
+    };
+
+    match obj.get("format") {
+        Some(Value::String(f)) if f == CONFIG_FORMAT => {}
+        Some(other) => err("format".into(), ImportProblem::WrongFormat { expected: CONFIG_FORMAT, got: other.to_string() }),
+        None => err("format".into(), ImportProblem::MissingFormat(CONFIG_FORMAT)),
+    }
+    match obj.get("version") {
+        Some(v) if v.as_u64().is_some_and(|v| (1..=CONFIG_VERSION).contains(&v)) => {}
+        Some(v) => err("version".into(), ImportProblem::UnsupportedVersion { got: v.to_string(), supported: CONFIG_VERSION }),
+        None => err("version".into(), ImportProblem::Missing),
+    }
+
+    let mode = match obj.get("mode") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(m)) => {
+            let parsed = ModeSetting::from_label(m);
+            if parsed.is_none() {
+                err("mode".into(), ImportProblem::UnknownMode(m.clone()));
+            }
+            parsed
+        }
+        Some(other) => {
+            err("mode".into(), ImportProblem::ExpectedString(other.to_string()));
+            None
+        }
+    };
+
+    let mut workspaces = Vec::new();
+    match obj.get("workspaces") {
+        Some(Value::Array(items)) if !items.is_empty() => {
+            for (wi, item) in items.iter().enumerate() {
+                if let Some(w) = parse_workspace(item, &format!("workspaces[{}]", wi), &mut err) {
+                    workspaces.push(Workspace { id: wi as u32 + 1, ..w });
+                }
+            }
+        }
+        Some(Value::Array(_)) => err("workspaces".into(), ImportProblem::NoWorkspaces),
+        Some(other) => err("workspaces".into(), ImportProblem::ExpectedArray(other.to_string())),
+        None => err("workspaces".into(), ImportProblem::Missing),
+    }
+
+    if errors.is_empty() {
+        Ok(ConfigDocument { mode, workspaces })
+    } else {
+        Err(errors)
+    }
+}
+
+fn parse_workspace(value: &Value, path: &str, err: &mut impl FnMut(String, ImportProblem)) -> Option<Workspace> {
+    let Some(obj) = value.as_object() else {
+        err(path.to_string(), ImportProblem::ExpectedObject(value.to_string()));
+        return None;
+    };
+
+    let name = match obj.get("name") {
+        Some(Value::String(n)) if !n.trim().is_empty() => n.trim().to_string(),
+        Some(Value::String(_)) => {
+            err(format!("{}.name", path), ImportProblem::Empty);
+            String::new()
+        }
+        Some(other) => {
+            err(format!("{}.name", path), ImportProblem::ExpectedString(other.to_string()));
+            String::new()
+        }
+        None => {
+            err(format!("{}.name", path), ImportProblem::Missing);
+            String::new()
+        }
+    };
+
+    let theme = match obj.get("theme") {
+        None | Some(Value::Null) => ThemeName::Minimalist,
+        Some(Value::String(t)) => ThemeName::from_key(t).unwrap_or_else(|| {
+            err(format!("{}.theme", path), ImportProblem::UnknownTheme(t.clone()));
+            ThemeName::Minimalist
+        }),
+        Some(other) => {
+            err(format!("{}.theme", path), ImportProblem::ExpectedString(other.to_string()));
+            ThemeName::Minimalist
+        }
+    };
+
+    let mut parsed: Vec<(TimezoneEntry, Option<usize>)> = Vec::new();
+    match obj.get("zones") {
+        Some(Value::Array(items)) if (1..=MAX_RINGS).contains(&items.len()) => {
+            for (zi, item) in items.iter().enumerate() {
+                let zpath = format!("{}.zones[{}]", path, zi);
+                if let Some((tz, color)) = parse_zone(item, &zpath, err) {
+                    if parsed.iter().any(|(z, _)| z.id == tz.id) {
+                        err(format!("{}.id", zpath), ImportProblem::DuplicateZone(tz.id));
+                    } else {
+                        parsed.push((tz, color));
+                    }
+                }
+            }
+        }
+        Some(Value::Array(items)) => {
+            err(format!("{}.zones", path), ImportProblem::ZoneCount(items.len()));
+        }
+        Some(other) => err(format!("{}.zones", path), ImportProblem::ExpectedArray(other.to_string())),
+        None => err(format!("{}.zones", path), ImportProblem::Missing),
+    }
+    let zones = ActiveTimezones::with_colors(parsed);
+
+    let mut meetings: Vec<Meeting> = Vec::new();
+    match obj.get("meetings") {
+        None | Some(Value::Null) => {}
+        Some(Value::Array(items)) => {
+            for (mi, item) in items.iter().enumerate() {
+                let mpath = format!("{}.meetings[{}]", path, mi);
+                if let Some(m) = parse_meeting(item, &mpath, zones.zones(), err) {
+                    if m.id != 0 && meetings.iter().any(|e| e.id == m.id) {
+                        err(format!("{}.id", mpath), ImportProblem::DuplicateMeetingId(m.id));
+                    } else {
+                        meetings.push(m);
+                    }
+                }
+            }
+        }
+        Some(other) => err(format!("{}.meetings", path), ImportProblem::ExpectedArray(other.to_string())),
+    }
+
+    // Meetings without an id get the next free one
+    let first_free = meetings.iter().map(|m| m.id).max().unwrap_or(0) + 1;
+    for (id, m) in (first_free..).zip(meetings.iter_mut().filter(|m| m.id == 0)) {
+        m.id = id;
+    }
+
+    let reference = match obj.get("reference") {
+        None | Some(Value::Null) => ReferenceZone::FirstRing,
+        Some(Value::String(r)) => match ReferenceZone::from_key(r) {
+            Some(ReferenceZone::Zone(id)) if !zones.zones().iter().any(|z| z.id == id) => {
+                err(format!("{}.reference", path), ImportProblem::NotInWorkspace(id.to_string()));
+                ReferenceZone::FirstRing
+            }
+            Some(reference) => reference,
+            None => {
+                err(format!("{}.reference", path), ImportProblem::UnknownReference(r.clone()));
+                ReferenceZone::FirstRing
+            }
+        },
+        Some(other) => {
+            err(format!("{}.reference", path), ImportProblem::ExpectedString(other.to_string()));
+            ReferenceZone::FirstRing
+        }
+    };
+
+    if zones.zones().is_empty() {
+        return None;
+    }
+    Some(Workspace { id: 0, name, zones, meetings, theme, reference })
+}
+
+fn parse_zone(value: &Value, path: &str, err: &mut impl FnMut(String, ImportProblem)) -> Option<(TimezoneEntry, Option<usize>)> {
+    let Some(obj) = value.as_object() else {
+        err(path.to_string(), ImportProblem::ExpectedZone(value.to_string()));
+        return None;
+    };
+    let entry = match obj.get("id") {
+        Some(Value::String(id)) => match zone_by_id(id) {
+            Some(tz) => Some(tz.clone()),
+            None => {
+                err(format!("{}.id", path), ImportProblem::UnknownZone(id.clone()));
+                None
+            }
+        },
+        Some(other) => {
+            err(format!("{}.id", path), ImportProblem::ExpectedString(other.to_string()));
+            None
+        }
+        None => {
+            err(format!("{}.id", path), ImportProblem::Missing);
+            None
+        }
+    };
+
+    let mut hour = |key: &str, default: f64, range: std::ops::RangeInclusive<f64>| match obj.get(key) {
+        None | Some(Value::Null) => Some(default),
+        Some(v) => match v.as_f64() {
+            Some(h) if range.contains(&h) => Some(h),
+            _ => {
+                err(format!("{}.{}", path, key), ImportProblem::HourOutOfRange { min: *range.start(), max: *range.end(), got: v.to_string() });
+                None
+            }
+        },
+    };
+    let work_start = hour("work_start", WORK_START, 0.0..=23.5);
+    let work_end = hour("work_end", WORK_END, 0.5..=24.0);
+    let color = match obj.get("color") {
+        None | Some(Value::Null) => None,
+        Some(v) => match v.as_u64().filter(|&c| c < MAX_RINGS as u64) {
+            Some(c) => Some(c as usize),
+            None => {
+                err(format!("{}.color", path), ImportProblem::NotAColorSlot(v.to_string()));
+                None
+            }
+        },
+    };
+
+    let (work_start, work_end) = (work_start?, work_end?);
+    if work_start >= work_end {
+        err(format!("{}.work_end", path), ImportProblem::EndNotAfterStart { start: work_start, end: work_end });
+        return None;
+    }
+    entry.map(|tz| (TimezoneEntry { work_start, work_end, ..tz }, color))
+}
+
+fn parse_meeting(
+    value: &Value,
+    path: &str,
+    zones: &[TimezoneEntry],
+    err: &mut impl FnMut(String, ImportProblem),
+) -> Option<Meeting> {
+    let Some(obj) = value.as_object() else {
+        err(path.to_string(), ImportProblem::ExpectedObject(value.to_string()));
+        return None;
+    };
+    let mut ok = true;
+
+    let title = match obj.get("title") {
+        Some(Value::String(t)) if !t.trim().is_empty() => t.clone(),
+        Some(Value::String(_)) => {
+            err(format!("{}.title", path), ImportProblem::Empty);
+            ok = false;
+            String::new()
+        }
+        Some(other) => {
+            err(format!("{}.title", path), ImportProblem::ExpectedString(other.to_string()));
+            ok = false;
+            String::new()
+        }
+        None => {
+            err(format!("{}.title", path), ImportProblem::Missing);
+            ok = false;
+            String::new()
+        }
+    };
+
+    let utc_hour = match obj.get("utc_hour") {
+        Some(v) if v.as_u64().is_some_and(|h| h < 24) => v.as_u64().unwrap_or(0) as u32,
+        Some(v) => {
+            err(format!("{}.utc_hour", path), ImportProblem::NotAUtcHour(v.to_string()));
+            ok = false;
+            0
+        }
+        None => {
+            err(format!("{}.utc_hour", path), ImportProblem::Missing);
+            ok = false;
+            0
+        }
+    };
+
+    let essential = match obj.get("essential") {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(b)) => *b,
+        Some(other) => {
+            err(format!("{}.essential", path), ImportProblem::ExpectedBool(other.to_string()));
+            ok = false;
+            false
+        }
+    };
+
+    let id = match obj.get("id") {
+        None | Some(Value::Null) => 0,
+        Some(v) => match v.as_u64().and_then(|id| u32::try_from(id).ok()) {
+            Some(id) => id,
+            None => {
+                err(format!("{}.id", path), ImportProblem::NotAnId(v.to_string()));
+                ok = false;
+                0
+            }
+        },
+    };
+
+    let organizer_zone = match obj.get("organizer_zone") {
+        None | Some(Value::Null) => zones.first().map(|z| z.id),
+        Some(Value::String(zid)) => match zones.iter().find(|z| z.id == zid) {
+            Some(tz) => Some(tz.id),
+            None => {
+                let problem = if zone_by_id(zid).is_some() { ImportProblem::NotInWorkspace(zid.clone()) } else { ImportProblem::UnknownZone(zid.clone()) };
+                err(format!("{}.organizer_zone", path), problem);
+                ok = false;
+                None
+            }
+        },
+        Some(other) => {
+            err(format!("{}.organizer_zone", path), ImportProblem::ExpectedString(other.to_string()));
+            ok = false;
+            None
+        }
+    };
+
+    if !ok {
+        return None;
+    }
+    Some(Meeting { id, utc_hour, title, essential, organizer_zone: organizer_zone? })
+}
+
+/// Combine imported workspaces with the existing ones. Imported workspaces
+/// always get fresh ids so they never alias an existing workspace.
+pub fn apply_import(existing: &[Workspace], imported: Vec<Workspace>, mode: ImportMode) -> Vec<Workspace> {
+    let mut result: Vec<Workspace> = match mode {
+        ImportMode::Replace => Vec::new(),
+        ImportMode::Merge => existing.to_vec(),
+    };
+    let mut next_id = next_workspace_id(existing);
+
+    for incoming in imported {
+        let target = match mode {
+            ImportMode::Merge => result.iter_mut().find(|w| w.name == incoming.name),
+            ImportMode::Replace => None,
+        };
+        match target {
+            Some(target) => {
+                for (i, tz) in incoming.zones.zones().iter().enumerate() {
+                    if target.zones.zones().len() < MAX_RINGS && !target.zones.zones().iter().any(|z| z.id == tz.id) {
+                        target.zones.push_with_color(tz.clone(), incoming.zones.palette_index(i));
+                    }
+                }
+                let fallback_organizer = target.zones.zones()[0].id;
+                for mut m in incoming.meetings {
+                    if target.meetings.iter().any(|e| e.title == m.title && e.utc_hour == m.utc_hour) {
+                        continue;
+                    }
+                    if target.meetings.iter().any(|e| e.id == m.id) {
+                        m.id = target.meetings.iter().map(|e| e.id).max().unwrap_or(0) + 1;
+                    }
+                    if !target.zones.zones().iter().any(|z| z.id == m.organizer_zone) {
+                        m.organizer_zone = fallback_organizer;
+                    }
+                    target.meetings.push(m);
+                }
+            }
+            None => {
+                result.push(Workspace { id: next_id, ..incoming });
+                next_id += 1;
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<Workspace> {
+        let mut w = Workspace::new(1, "Team");
+        w.theme = ThemeName::Bold;
+        w.reference = ReferenceZone::Utc;
+        w.zones.set_work_hours(0, 8.0, WORK_END);
+        w.meetings.push(Meeting {
+            id: 7,
+            utc_hour: 15,
+            title: "Sync".to_string(),
+            essential: true,
+            organizer_zone: "europe_london",
+        });
+        vec![w]
+    }
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let json = export_config(&sample(), ModeSetting::Sunset);
+        let doc = parse_config(&json).unwrap();
+        assert_eq!(doc.mode, Some(ModeSetting::Sunset));
+        assert_eq!(doc.workspaces, sample());
+    }
+
+    #[test]
+    fn test_precise_errors() {
+        let json = r#"{
+            "format": "tz-clock-config", "version": 1, "mode": "Dim",
+            "workspaces": [{
+                "name": "A", "theme": "Neon",
+                "zones": [{"id": "europe_lndon"}, {"id": "asia_tokyo", "work_start": 19, "work_end": 10}],
+                "meetings": [{"title": "x", "utc_hour": 24, "organizer_zone": "europe_paris"}]
+            }]
+        }"#;
+        let errors: Vec<String> = parse_config(json).unwrap_err().iter().map(|e| e.to_string()).collect();
+        assert_eq!(errors, vec![
+            "mode: expected \"Light\", \"Dark\", \"System\" or \"Sunset\", got \"Dim\"",
+            "workspaces[0].theme: unknown theme \"Neon\" (expected one of Minimalist, Bold, Professional, Playful, High-Contrast, Colorblind-Safe)",
+            "workspaces[0].zones[0].id: unknown zone id \"europe_lndon\"",
+            "workspaces[0].zones[1].work_end: must be after work_start (19 >= 10)",
+            "workspaces[0].meetings[0].utc_hour: expected a whole hour from 0 to 23, got 24",
+            "workspaces[0].meetings[0].organizer_zone: \"europe_paris\" is not one of this workspace's zones",
+        ]);
+        assert_eq!(parse_config("{").unwrap_err().len(), 1);
+        assert_eq!(parse_config("[]").unwrap_err()[0].problem, ImportProblem::NotAnObject);
+    }
+
+    #[test]
+    fn test_replace_and_merge() {
+        let existing = sample();
+        let mut incoming = Workspace::new(1, "Team");
+        incoming.zones = ActiveTimezones::with_colors(vec![(zone_by_id("asia_tokyo").unwrap().clone(), Some(5))]);
+        incoming.meetings.push(Meeting {
+            id: 7,
+            utc_hour: 1,
+            title: "Tokyo standup".to_string(),
+            essential: false,
+            organizer_zone: "asia_tokyo",
+        });
+        let other = Workspace::new(1, "Other");
+
+        let replaced = apply_import(&existing, vec![incoming.clone(), other.clone()], ImportMode::Replace);
+        assert_eq!(replaced.iter().map(|w| w.id).collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(replaced[0].zones.zones().len(), 1);
+
+        let merged = apply_import(&existing, vec![incoming, other], ImportMode::Merge);
+        assert_eq!(merged.len(), 2);
+        let team = &merged[0];
+        assert_eq!(team.zones.zones().len(), 4);
+        // Merged zones keep their color when it is free
+        assert_eq!(team.zones.palette_index(3), 5);
+        assert_eq!(team.meetings.len(), 2);
+        assert_eq!(team.meetings[1].id, 8);
+        assert_eq!(merged[1].id, 2);
+    }
+
+    #[test]
+    fn test_duplicate_meeting_ids_and_color_bounds() {
+        let json = r#"{
+            "format": "tz-clock-config", "version": 1,
+            "workspaces": [{
+                "name": "A",
+                "zones": [{"id": "europe_london", "color": 12}],
+                "meetings": [{"id": 3, "title": "x", "utc_hour": 9}, {"id": 3, "title": "y", "utc_hour": 10}, {"title": "z", "utc_hour": 11}]
+            }]
+        }"#;
+        let errors = parse_config(json).unwrap_err();
+        assert_eq!(errors, vec![
+            ImportError { path: "workspaces[0].zones[0].color".into(), problem: ImportProblem::NotAColorSlot("12".into()) },
+            ImportError { path: "workspaces[0].meetings[1].id".into(), problem: ImportProblem::DuplicateMeetingId(3) },
+        ]);
+        assert_eq!(errors[0].to_string(), "workspaces[0].zones[0].color: expected a palette slot from 0 to 11, got 12");
+    }
+}
@@ -0,0 +1,401 @@
+//! User-defined themes and their JSON form:
+//!
+//! ```json
+//! {
+//!   "format": "tz-clock-theme",
+//!   "version": 1,
+//!   "name": "Acme",
+//!   "light": { "background": "#ffffff", "button_primary_bg": "#e30613", "ring_1": "#fde8e9" },
+//!   "dark": { "background": "#111111" }
+//! }
+//! ```
+//!
+//! Palette keys are [`COLOR_KEYS`]; colors are `#rgb` or `#rrggbb`, and any
+//! key left out keeps the Minimalist color for that mode. The browser's
+//! theme list is stored as an array of the same objects plus an `id`.
+//!
+//! Saved themes hold `&'static str` colors like the built-in ones, so their
+//! colors are interned: each distinct color is leaked once and reused. A
+//! theme open in the editor keeps its edits owned in a [`ThemeDraft`] and is
+//! only interned when saved.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashSet};
+use serde_json::Value;
+use crate::modules::config_io::{ImportError, ImportProblem};
+use crate::modules::themes::{get_theme, Mode, Palette, ThemeColors, ThemeName, COLOR_KEYS};
+
+pub const THEME_FORMAT: &str = "tz-clock-theme";
+pub const THEME_VERSION: u64 = 1;
+
+/// WCAG AA minimum for normal-size text
+pub const MIN_TEXT_CONTRAST: f64 = 4.5;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CustomTheme {
+    pub id: u32,
+    pub name: String,
+    pub light: ThemeColors,
+    pub dark: ThemeColors,
+}
+
+impl CustomTheme {
+    /// A new theme starting from both palettes of `base`
+    pub fn from_base(id: u32, name: &str, base: ThemeName, custom: &[CustomTheme]) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            light: resolve_theme(base, Mode::Light, custom),
+            dark: resolve_theme(base, Mode::Dark, custom),
+        }
+    }
+
+    pub fn palette(&self, mode: Mode) -> &ThemeColors {
+        match mode {
+            Mode::Light => &self.light,
+            Mode::Dark => &self.dark,
+        }
+    }
+}
+
+/// A theme open in the editor, previewed everywhere until it is saved
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeDraft {
+    pub id: u32,
+    pub name: String,
+    pub light: DraftPalette,
+    pub dark: DraftPalette,
+}
+
+/// Colors picked in the editor, owned, over the palette it started from
+#[derive(Debug, Clone, PartialEq)]
+pub struct DraftPalette {
+    base: ThemeColors,
+    edits: BTreeMap<&'static str, String>,
+}
+
+impl From<ThemeColors> for DraftPalette {
+    fn from(base: ThemeColors) -> Self {
+        Self { base, edits: BTreeMap::new() }
+    }
+}
+
+impl DraftPalette {
+    /// Ignores unknown keys
+    pub fn set_color(&mut self, key: &'static str, color: String) {
+        if self.base.color(key).is_some() {
+            self.edits.insert(key, color);
+        }
+    }
+
+    fn intern(&self) -> ThemeColors {
+        let mut colors = self.base;
+        for (key, color) in &self.edits {
+            colors.set_color(key, intern_color(color));
+        }
+        colors
+    }
+}
+
+impl Palette for DraftPalette {
+    fn color(&self, key: &str) -> Option<&str> {
+        self.edits.get(key).map(String::as_str).or_else(|| self.base.color(key))
+    }
+}
+
+impl From<CustomTheme> for ThemeDraft {
+    fn from(theme: CustomTheme) -> Self {
+        Self { id: theme.id, name: theme.name, light: theme.light.into(), dark: theme.dark.into() }
+    }
+}
+
+impl ThemeDraft {
+    pub fn palette(&self, mode: Mode) -> &DraftPalette {
+        match mode {
+            Mode::Light => &self.light,
+            Mode::Dark => &self.dark,
+        }
+    }
+
+    pub fn palette_mut(&mut self, mode: Mode) -> &mut DraftPalette {
+        match mode {
+            Mode::Light => &mut self.light,
+            Mode::Dark => &mut self.dark,
+        }
+    }
+
+    /// The theme to save, with its colors interned
+    pub fn to_theme(&self) -> CustomTheme {
+        CustomTheme { id: self.id, name: self.name.clone(), light: self.light.intern(), dark: self.dark.intern() }
+    }
+}
+
+thread_local! {
+    static COLORS: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+}
+
+/// Leak-once storage for a normalized color string
+fn intern_color(color: &str) -> &'static str {
+    COLORS.with(|c| {
+        let mut colors = c.borrow_mut();
+        if let Some(existing) = colors.get(color) {
+            return *existing;
+        }
+        let leaked: &'static str = color.to_string().leak();
+        colors.insert(leaked);
+        leaked
+    })
+}
+
+/// Colors for any theme; a custom theme that no longer exists falls back to Minimalist
+pub fn resolve_theme(name: ThemeName, mode: Mode, custom: &[CustomTheme]) -> ThemeColors {
+    match name {
+        ThemeName::Custom(id) => custom.iter()
+            .find(|t| t.id == id)
+            .map_or_else(|| *get_theme(ThemeName::Minimalist, mode), |t| *t.palette(mode)),
+        builtin => *get_theme(builtin, mode),
+    }
+}
+
+pub fn next_theme_id(themes: &[CustomTheme]) -> u32 {
+    themes.iter().map(|t| t.id).max().unwrap_or(0) + 1
+}
+
+/// `#rgb` or `#rrggbb` as lowercase `#rrggbb`, the form color inputs use
+pub fn normalize_color(raw: &str) -> Option<String> {
+    let hex = raw.trim().strip_prefix('#')?;
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        3 => Some(format!("#{}", hex.chars().flat_map(|c| [c, c]).collect::<String>().to_ascii_lowercase())),
+        6 => Some(format!("#{}", hex.to_ascii_lowercase())),
+        _ => None,
+    }
+}
+
+fn relative_luminance(color: &str) -> Option<f64> {
+    let hex = normalize_color(color)?;
+    let channel = |i: usize| {
+        let c = u8::from_str_radix(&hex[i..i + 2], 16).ok()? as f64 / 255.0;
+        Some(if c <= 0.03928 { c / 12.92 } else { ((c + 0.055) / 1.055).powf(2.4) })
+    };
+    Some(0.2126 * channel(1)? + 0.7152 * channel(3)? + 0.0722 * channel(5)?)
+}
+
+/// WCAG contrast ratio between two colors, from 1 to 21
+pub fn contrast_ratio(a: &str, b: &str) -> Option<f64> {
+    let (la, lb) = (relative_luminance(a)?, relative_luminance(b)?);
+    Some((la.max(lb) + 0.05) / (la.min(lb) + 0.05))
+}
+
+/// A text color that is hard to read on the background it is drawn over
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContrastIssue {
+    pub foreground: &'static str,
+    pub background: &'static str,
+    pub ratio: f64,
+}
+
+/// Text/background key pairs the UI actually draws
+const TEXT_PAIRS: [(&str, &str); 17] = [
+    ("text_primary", "background"),
+    ("text_secondary", "background"),
+    ("text_primary", "card_bg"),
+    ("button_primary_text", "button_primary_bg"),
+    ("success_text_dark", "success_bg"),
+    ("warning_text_dark", "warning_bg"),
+    ("text_primary", "ring_1"),
+    ("text_primary", "ring_2"),
+    ("text_primary", "ring_3"),
+    ("text_primary", "ring_4"),
+    ("text_primary", "ring_5"),
+    ("text_primary", "working_hours"),
+    ("ring_1_text", "center_circle_bg"),
+    ("ring_2_text", "center_circle_bg"),
+    ("ring_3_text", "center_circle_bg"),
+    ("ring_4_text", "center_circle_bg"),
+    ("ring_5_text", "center_circle_bg"),
+];
+
+pub fn contrast_issues(colors: &impl Palette) -> Vec<ContrastIssue> {
+    TEXT_PAIRS.iter().filter_map(|&(foreground, background)| {
+        let ratio = contrast_ratio(colors.color(foreground)?, colors.color(background)?)?;
+        (ratio < MIN_TEXT_CONTRAST).then_some(ContrastIssue { foreground, background, ratio })
+    }).collect()
+}
+
+fn palette_to_value(colors: &impl Palette) -> Value {
+    let map: BTreeMap<&str, &str> = COLOR_KEYS.iter().filter_map(|k| Some((*k, colors.color(k)?))).collect();
+    serde_json::to_value(map).unwrap_or(Value::Null)
+}
+
+fn theme_to_value(id: Option<u32>, name: &str, light: &impl Palette, dark: &impl Palette) -> Value {
+    let mut obj = serde_json::Map::new();
+    match id {
+        Some(id) => {
+            obj.insert("id".into(), id.into());
+        }
+        None => {
+            obj.insert("format".into(), THEME_FORMAT.into());
+            obj.insert("version".into(), THEME_VERSION.into());
+        }
+    }
+    obj.insert("name".into(), name.into());
+    obj.insert("light".into(), palette_to_value(light));
+    obj.insert("dark".into(), palette_to_value(dark));
+    Value::Object(obj)
+}
+
+/// A single theme as a shareable file
+pub fn export_theme(theme: &ThemeDraft) -> String {
+    serde_json::to_string_pretty(&theme_to_value(None, &theme.name, &theme.light, &theme.dark)).unwrap_or_default()
+}
+
+fn palette_from_value(value: Option<&Value>, mode: Mode, path: &str, errors: &mut Vec<ImportError>) -> DraftPalette {
+    let mut colors = DraftPalette::from(*get_theme(ThemeName::Minimalist, mode));
+    let mut err = |path: String, problem: ImportProblem| errors.push(ImportError { path, problem });
+    match value {
+        None | Some(Value::Null) => {}
+        Some(Value::Object(map)) => {
+            for (key, raw) in map {
+                let key_path = format!("{}.{}", path, key);
+                let Some(key) = COLOR_KEYS.iter().find(|k| **k == key) else {
+                    err(key_path, ImportProblem::UnknownColorKey);
+                    continue;
+                };
+                match raw.as_str().and_then(normalize_color) {
+                    Some(color) => colors.set_color(key, color),
+                    None => err(key_path, ImportProblem::NotAColor(raw.to_string())),
+                }
+            }
+        }
+        Some(other) => err(path.to_string(), ImportProblem::ExpectedObject(other.to_string())),
+    }
+    colors
+}
+
+fn theme_from_value(value: &Value, path: &str, errors: &mut Vec<ImportError>) -> ThemeDraft {
+    let field = |key: &str| value.get(key);
+    let name = match field("name") {
+        Some(Value::String(s)) if !s.trim().is_empty() => s.trim().to_string(),
+        other => {
+            let problem = match other {
+                Some(Value::String(_)) => ImportProblem::Empty,
+                Some(other) => ImportProblem::ExpectedString(other.to_string()),
+                None => ImportProblem::Missing,
+            };
+            errors.push(ImportError { path: format!("{}name", path), problem });
+            String::new()
+        }
+    };
+    ThemeDraft {
+        id: field("id").and_then(Value::as_u64).map_or(0, |id| id as u32),
+        name,
+        light: palette_from_value(field("light"), Mode::Light, &format!("{}light", path), errors),
+        dark: palette_from_value(field("dark"), Mode::Dark, &format!("{}dark", path), errors),
+    }
+}
+
+/// Validate a theme file, collecting every problem. The id is a placeholder.
+/// The theme opens as a draft, so nothing is interned unless it is saved.
+pub fn parse_theme(raw: &str) -> Result<ThemeDraft, Vec<ImportError>> {
+    let root: Value = serde_json::from_str(raw).map_err(|e| {
+        vec![ImportError { path: String::new(), problem: ImportProblem::InvalidJson(e.to_string()) }]
+    })?;
+    let mut errors = Vec::new();
+    let mut err = |path: &str, problem: ImportProblem| errors.push(ImportError { path: path.into(), problem });
+    match root.get("format") {
+        Some(Value::String(f)) if f == THEME_FORMAT => {}
+        Some(other) => err("format", ImportProblem::WrongFormat { expected: THEME_FORMAT, got: other.to_string() }),
+        None => err("format", ImportProblem::MissingFormat(THEME_FORMAT)),
+    }
+    match root.get("version") {
+        Some(v) if v.as_u64() == Some(THEME_VERSION) => {}
+        Some(v) => err("version", ImportProblem::UnsupportedVersion { got: v.to_string(), supported: THEME_VERSION }),
+        None => err("version", ImportProblem::Missing),
+    }
+    let theme = theme_from_value(&root, "", &mut errors);
+    if errors.is_empty() { Ok(theme) } else { Err(errors) }
+}
+
+pub fn custom_themes_to_json(themes: &[CustomTheme]) -> String {
+    Value::Array(themes.iter().map(|t| theme_to_value(Some(t.id), &t.name, &t.light, &t.dark)).collect()).to_string()
+}
+
+/// Stored themes; entries with problems keep whatever parsed
+pub fn custom_themes_from_json(raw: &str) -> Option<Vec<CustomTheme>> {
+    let items: Vec<Value> = serde_json::from_str(raw).ok()?;
+    Some(items.iter()
+        .map(|item| theme_from_value(item, "", &mut Vec::new()))
+        .filter(|t| t.id != 0)
+        .map(|t| t.to_theme())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::themes::theme_stylesheet;
+
+    #[test]
+    fn test_contrast() {
+        assert_eq!(contrast_ratio("#000", "#ffffff").map(|r| r.round()), Some(21.0));
+        assert_eq!(contrast_ratio("#777777", "#777777"), Some(1.0));
+        assert_eq!(normalize_color(" #ABC "), Some("#aabbcc".to_string()));
+        assert_eq!(normalize_color("red"), None);
+
+        let mut colors = *get_theme(ThemeName::Minimalist, Mode::Light);
+        colors.set_color("button_primary_text", "#007acc");
+        let issues = contrast_issues(&colors);
+        assert!(issues.iter().any(|i| i.foreground == "button_primary_text" && i.background == "button_primary_bg"));
+
+        // The accessibility palettes pass every check they are meant for
+        for name in [ThemeName::HighContrast, ThemeName::ColorblindSafe] {
+            for mode in [Mode::Light, Mode::Dark] {
+                assert_eq!(contrast_issues(get_theme(name, mode)), vec![], "{:?} {:?}", name, mode);
+            }
+        }
+    }
+
+    #[test]
+    fn test_theme_round_trip() {
+        let mut draft = ThemeDraft::from(CustomTheme::from_base(3, "Acme", ThemeName::Bold, &[]));
+        draft.light.set_color("ring_2", "#e30613".to_string());
+        let theme = draft.to_theme();
+        assert_eq!(theme.light.ring_defaults[1], "#e30613");
+
+        let mut parsed = parse_theme(&export_theme(&draft)).unwrap();
+        parsed.id = 3;
+        assert_eq!(parsed.to_theme(), theme);
+        assert_eq!(custom_themes_from_json(&custom_themes_to_json(std::slice::from_ref(&theme))), Some(vec![theme.clone()]));
+
+        let custom = [theme];
+        assert_eq!(resolve_theme(ThemeName::Custom(3), Mode::Light, &custom).ring_defaults[1], "#e30613");
+        assert_eq!(resolve_theme(ThemeName::Custom(9), Mode::Dark, &custom), *get_theme(ThemeName::Minimalist, Mode::Dark));
+    }
+
+    #[test]
+    fn test_drafts_are_not_interned() {
+        let mut draft = ThemeDraft::from(CustomTheme::from_base(1, "Draft", ThemeName::Playful, &[]));
+        let interned = || COLORS.with(|c| c.borrow().len());
+        let before = interned();
+        for shade in 0..50 {
+            draft.dark.set_color("background", format!("#0000{:02x}", shade));
+        }
+        assert_eq!(draft.dark.color("background"), Some("#000031"));
+        assert!(theme_stylesheet(&draft.dark, ":root").contains("--tz-background: #000031;"));
+        assert_eq!(interned(), before);
+
+        // Saving interns only the colors it keeps
+        assert_eq!(draft.to_theme().dark.background, "#000031");
+        assert!(interned() <= before + 1);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let raw = r##"{"format":"tz-clock-theme","version":1,"name":"X","light":{"background":"blue","shadow":"#000"}}"##;
+        let paths: Vec<String> = parse_theme(raw).unwrap_err().into_iter().map(|e| e.path).collect();
+        assert_eq!(paths, vec!["light.background", "light.shadow"]);
+    }
+}
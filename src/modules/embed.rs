@@ -0,0 +1,123 @@
+use crate::modules::themes::{Mode, ThemeName};
+use crate::modules::timezone_db::TIMEZONE_DATABASE;
+use crate::modules::types::{TimezoneEntry, MAX_RINGS};
+
+/// Settings for the compact iframe widget, read from the page's query string,
+/// e.g. `?embed&zones=america_chicago,europe_london&theme=bold&mode=light&size=240&bg=transparent&hatch`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EmbedConfig {
+    pub zones: Option<Vec<TimezoneEntry>>,
+    pub theme: Option<ThemeName>,
+    pub mode: Option<Mode>,
+    pub size: Option<u32>,
+    pub transparent: bool,
+    /// Stripe working hours as well as coloring them
+    pub hatch: bool,
+}
+
+/// Parse a query string (with or without the leading `?`).
+/// Returns `None` unless the `embed` parameter is present.
+pub fn parse_embed_query(query: &str) -> Option<EmbedConfig> {
+    let params: Vec<(String, String)> = query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode_component(key), decode_component(value))
+        })
+        .collect();
+
+    let get = |name: &str| params.iter().find(|(k, _)| k == name).map(|(_, v)| v.as_str());
+
+    match get("embed") {
+        None | Some("0") | Some("false") => return None,
+        _ => {}
+    }
+
+    let zones = get("zones").map(parse_zone_ids).filter(|zones| !zones.is_empty());
+
+    Some(EmbedConfig {
+        zones,
+        theme: get("theme").and_then(ThemeName::from_label),
+        mode: get("mode").and_then(Mode::from_label),
+        size: get("size").and_then(|s| s.parse::<u32>().ok()).map(|s| s.clamp(120, 800)),
+        transparent: matches!(get("bg"), Some("transparent") | Some("none")),
+        hatch: matches!(get("hatch"), Some("" | "1" | "true")),
+    })
+}
+
+/// Resolve a comma-separated list of zone ids against the database,
+/// skipping unknown ids and duplicates and keeping at most `MAX_RINGS`.
+pub fn parse_zone_ids(raw: &str) -> Vec<TimezoneEntry> {
+    let mut zones: Vec<TimezoneEntry> = Vec::new();
+    for id in raw.split(',').map(str::trim) {
+        if let Some(tz) = TIMEZONE_DATABASE.iter().find(|tz| tz.id == id) {
+            if zones.len() < MAX_RINGS && !zones.iter().any(|z| z.id == tz.id) {
+                zones.push(tz.clone());
+            }
+        }
+    }
+    zones
+}
+
+/// Minimal `application/x-www-form-urlencoded` decoding (`+` and `%XX`).
+fn decode_component(raw: &str) -> String {
+    let bytes = raw.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = |b: u8| (b as char).to_digit(16);
+                match (hex(bytes[i + 1]), hex(bytes[i + 2])) {
+                    (Some(hi), Some(lo)) => {
+                        out.push((hi * 16 + lo) as u8);
+                        i += 2;
+                    }
+                    _ => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_not_embedded_without_flag() {
+        assert_eq!(parse_embed_query(""), None);
+        assert_eq!(parse_embed_query("?zones=europe_london"), None);
+        assert_eq!(parse_embed_query("?embed=0"), None);
+    }
+
+    #[test]
+    fn test_full_query() {
+        let cfg = parse_embed_query("?embed&zones=america_chicago%2Ceurope_london,bogus&theme=bold&mode=light&size=240&bg=transparent&hatch").unwrap();
+        let ids: Vec<&str> = cfg.zones.unwrap().iter().map(|z| z.id).collect();
+        assert_eq!(ids, vec!["america_chicago", "europe_london"]);
+        assert_eq!(cfg.theme, Some(ThemeName::Bold));
+        assert_eq!(cfg.mode, Some(Mode::Light));
+        assert_eq!(cfg.size, Some(240));
+        assert!(cfg.transparent);
+        assert!(cfg.hatch);
+    }
+
+    #[test]
+    fn test_defaults_and_bad_values() {
+        let cfg = parse_embed_query("embed=1&zones=nope&theme=neon&size=abc").unwrap();
+        assert_eq!(parse_embed_query("?embed&theme=high-contrast").unwrap().theme, Some(ThemeName::HighContrast));
+        assert_eq!(cfg.zones, None);
+        assert_eq!(cfg.theme, None);
+        assert_eq!(cfg.mode, None);
+        assert_eq!(cfg.size, None);
+        assert!(!cfg.transparent);
+        assert!(!cfg.hatch);
+    }
+}
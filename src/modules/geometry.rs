@@ -39,16 +39,68 @@ pub fn label_position(cx: f64, cy: f64, outer_r: f64, inner_r: f64, hour: f64) -
 
 use crate::modules::types::{BG_R, CENTER_R, CENTER_GAP, RING_GAP, RingGeometry};
 
-/// Calculate ring geometries for N rings (1..=5).
-/// Distributes available radial space evenly with gaps between rings.
+/// Calculate ring geometries for N rings.
+/// Distributes available radial space evenly with gaps between rings; the
+/// gaps narrow once there are more than six rings.
 pub fn compute_ring_geometries(n: usize) -> Vec<RingGeometry> {
     let available = BG_R - CENTER_R - CENTER_GAP; // 196 - 54 - 16 = 126
-    let total_gap = RING_GAP * (n as f64 - 1.0);
+    let gap = RING_GAP.min(available / n as f64 / 5.0);
+    let total_gap = gap * (n as f64 - 1.0);
     let thickness = (available - total_gap) / n as f64;
 
     (0..n).map(|i| {
-        let outer_r = BG_R - RING_GAP - (i as f64 * (thickness + RING_GAP));
+        let outer_r = BG_R - RING_GAP - (i as f64 * (thickness + gap));
         let inner_r = outer_r - thickness;
         RingGeometry { outer_r, inner_r }
     }).collect()
 }
+
+/// Every how many hours a ring is labelled: every other hour on rings too
+/// thin to fit 24 labels
+pub fn label_step(geo: &RingGeometry) -> u32 {
+    if geo.outer_r - geo.inner_r < 12.0 { 2 } else { 1 }
+}
+
+/// Segment reached from `(ring, hour)` by a navigation key: left/right step
+/// around the dial, up/down move to the outer/inner ring.
+pub fn dial_neighbor((ring, hour): (usize, u32), key: &str, ring_count: usize) -> Option<(usize, u32)> {
+    match key {
+        "ArrowRight" => Some((ring, (hour + 1) % 24)),
+        "ArrowLeft" => Some((ring, (hour + 23) % 24)),
+        "ArrowUp" => Some((ring.saturating_sub(1), hour)),
+        "ArrowDown" => Some(((ring + 1).min(ring_count.saturating_sub(1)), hour)),
+        "Home" => Some((ring, 0)),
+        "End" => Some((ring, 23)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dial_neighbor() {
+        assert_eq!(dial_neighbor((0, 23), "ArrowRight", 3), Some((0, 0)));
+        assert_eq!(dial_neighbor((1, 0), "ArrowLeft", 3), Some((1, 23)));
+        assert_eq!(dial_neighbor((0, 5), "ArrowUp", 3), Some((0, 5)));
+        assert_eq!(dial_neighbor((2, 5), "ArrowDown", 3), Some((2, 5)));
+        assert_eq!(dial_neighbor((1, 5), "ArrowDown", 3), Some((2, 5)));
+        assert_eq!(dial_neighbor((1, 5), "Tab", 3), None);
+    }
+
+    #[test]
+    fn test_ring_geometries() {
+        use crate::modules::types::MAX_RINGS;
+        for n in 1..=MAX_RINGS {
+            let geos = compute_ring_geometries(n);
+            assert_eq!(geos.len(), n);
+            assert!(geos.windows(2).all(|w| w[1].outer_r < w[0].inner_r));
+            assert!((geos[n - 1].inner_r - (CENTER_R + CENTER_GAP - RING_GAP)).abs() < 1e-9);
+        }
+        // Five rings keep the original spacing and every label
+        assert_eq!(compute_ring_geometries(5)[1].outer_r, BG_R - RING_GAP - 26.0);
+        assert_eq!(label_step(&compute_ring_geometries(5)[4]), 1);
+        assert_eq!(label_step(&compute_ring_geometries(MAX_RINGS)[0]), 2);
+    }
+}
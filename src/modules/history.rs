@@ -0,0 +1,201 @@
+use crate::modules::themes::{ModeSetting, ThemeName};
+use crate::modules::types::{ActiveTimezones, Meeting, TimezoneEntry};
+
+/// Edits landing within this many milliseconds of the previous one are
+/// folded into the same undo step (e.g. clicking through several themes).
+pub const COALESCE_MS: f64 = 1000.0;
+
+/// Oldest entries are dropped beyond this depth.
+pub const HISTORY_LIMIT: usize = 100;
+
+/// What a destructive edit threw away; worded by the UI in its current language.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Removal {
+    Zones(Vec<TimezoneEntry>),
+    Meeting(String),
+    Meetings(usize),
+}
+
+/// Everything undo/redo restores.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppSnapshot {
+    pub zones: ActiveTimezones,
+    pub meetings: Vec<Meeting>,
+    pub theme: ThemeName,
+    pub mode: ModeSetting,
+}
+
+impl AppSnapshot {
+    /// Describe a change from `self` to `next` if it threw something away,
+    /// so the UI can offer an immediate "Undo".
+    pub fn destructive_change(&self, next: &AppSnapshot) -> Option<Removal> {
+        if next.zones.zones().len() < self.zones.zones().len() {
+            let removed: Vec<TimezoneEntry> = self.zones.zones().iter()
+                .filter(|tz| !next.zones.zones().iter().any(|n| n.id == tz.id))
+                .cloned()
+                .collect();
+            return Some(Removal::Zones(removed));
+        }
+        let deleted: Vec<&str> = self.meetings.iter()
+            .filter(|m| !next.meetings.iter().any(|n| n.id == m.id))
+            .map(|m| m.title.as_str())
+            .collect();
+        match deleted.len() {
+            0 => None,
+            1 => Some(Removal::Meeting(deleted[0].to_string())),
+            n => Some(Removal::Meetings(n)),
+        }
+    }
+}
+
+/// Linear undo/redo stacks of snapshots.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: Vec<AppSnapshot>,
+    redo: Vec<AppSnapshot>,
+    last_record_ms: Option<f64>,
+}
+
+impl History {
+    /// Record that the state is about to move on from `previous`.
+    /// Any redo branch is discarded.
+    pub fn record(&mut self, previous: AppSnapshot, now_ms: f64) {
+        let coalesce = self.last_record_ms.is_some_and(|last| now_ms - last < COALESCE_MS)
+            && !self.undo.is_empty();
+        self.last_record_ms = Some(now_ms);
+        self.redo.clear();
+        if !coalesce {
+            self.push_undo(previous);
+        }
+    }
+
+    /// Record a step that is never folded into its neighbours, used for
+    /// destructive edits so "Undo" always brings back what was removed.
+    pub fn checkpoint(&mut self, previous: AppSnapshot) {
+        self.last_record_ms = None;
+        self.redo.clear();
+        self.push_undo(previous);
+    }
+
+    fn push_undo(&mut self, snapshot: AppSnapshot) {
+        self.undo.push(snapshot);
+        if self.undo.len() > HISTORY_LIMIT {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Step back, returning the snapshot to restore.
+    pub fn undo(&mut self, current: AppSnapshot) -> Option<AppSnapshot> {
+        let previous = self.undo.pop()?;
+        self.redo.push(current);
+        self.last_record_ms = None;
+        Some(previous)
+    }
+
+    /// Step forward again after an undo.
+    pub fn redo(&mut self, current: AppSnapshot) -> Option<AppSnapshot> {
+        let next = self.redo.pop()?;
+        self.undo.push(current);
+        self.last_record_ms = None;
+        Some(next)
+    }
+
+    /// Forget every step after another tab replaced the state. Undoing past
+    /// the remote edit would restore a stale snapshot and save it over theirs.
+    pub fn adopt_remote(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(theme: ThemeName) -> AppSnapshot {
+        AppSnapshot {
+            zones: ActiveTimezones::default(),
+            meetings: Vec::new(),
+            theme,
+            mode: ModeSetting::Dark,
+        }
+    }
+
+    #[test]
+    fn test_undo_redo_round_trip() {
+        let mut h = History::default();
+        h.record(snapshot(ThemeName::Minimalist), 0.0);
+        h.record(snapshot(ThemeName::Bold), 5000.0);
+
+        assert_eq!(h.undo(snapshot(ThemeName::Playful)), Some(snapshot(ThemeName::Bold)));
+        assert_eq!(h.undo(snapshot(ThemeName::Bold)), Some(snapshot(ThemeName::Minimalist)));
+        assert_eq!(h.undo(snapshot(ThemeName::Minimalist)), None);
+        assert_eq!(h.redo(snapshot(ThemeName::Minimalist)), Some(snapshot(ThemeName::Bold)));
+        assert_eq!(h.redo(snapshot(ThemeName::Bold)), Some(snapshot(ThemeName::Playful)));
+        assert!(!h.can_redo());
+    }
+
+    #[test]
+    fn test_rapid_edits_coalesce() {
+        let mut h = History::default();
+        h.record(snapshot(ThemeName::Minimalist), 0.0);
+        h.record(snapshot(ThemeName::Bold), 300.0);
+        h.record(snapshot(ThemeName::Professional), 600.0);
+
+        assert_eq!(h.undo(snapshot(ThemeName::Playful)), Some(snapshot(ThemeName::Minimalist)));
+        assert!(!h.can_undo());
+    }
+
+    #[test]
+    fn test_checkpoint_is_never_coalesced() {
+        let mut h = History::default();
+        h.record(snapshot(ThemeName::Minimalist), 0.0);
+        h.checkpoint(snapshot(ThemeName::Bold));
+        h.record(snapshot(ThemeName::Professional), 100.0);
+
+        assert_eq!(h.undo(snapshot(ThemeName::Playful)), Some(snapshot(ThemeName::Professional)));
+        assert_eq!(h.undo(snapshot(ThemeName::Professional)), Some(snapshot(ThemeName::Bold)));
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let mut h = History::default();
+        h.record(snapshot(ThemeName::Minimalist), 0.0);
+        h.undo(snapshot(ThemeName::Bold));
+        assert!(h.can_redo());
+        h.record(snapshot(ThemeName::Minimalist), 5000.0);
+        assert!(!h.can_redo());
+    }
+
+    #[test]
+    fn test_remote_update_clears_history() {
+        let mut h = History::default();
+        h.record(snapshot(ThemeName::Minimalist), 0.0);
+        h.record(snapshot(ThemeName::Bold), 5000.0);
+        h.undo(snapshot(ThemeName::Professional));
+
+        // Another tab saved Playful; nothing from before it can be restored
+        h.adopt_remote();
+        assert!(!h.can_undo() && !h.can_redo());
+        h.record(snapshot(ThemeName::Playful), 5100.0);
+        assert_eq!(h.undo(snapshot(ThemeName::Bold)), Some(snapshot(ThemeName::Playful)));
+        assert!(!h.can_undo());
+    }
+
+    #[test]
+    fn test_destructive_change() {
+        let before = snapshot(ThemeName::Minimalist);
+        let mut after = before.clone();
+        let london = after.zones.zones()[2].clone();
+        after.zones.remove(2);
+        assert_eq!(before.destructive_change(&after), Some(Removal::Zones(vec![london])));
+        assert_eq!(before.destructive_change(&snapshot(ThemeName::Bold)), None);
+    }
+}
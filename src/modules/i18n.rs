@@ -0,0 +1,1067 @@
+//! UI message catalog. Every language is an exhaustive `match` over [`Msg`],
+//! so adding a message without translating it fails to compile. `{}` marks
+//! a placeholder, filled in order by [`Language::fill`].
+
+use crate::modules::config_io::{ImportError, ImportProblem};
+use crate::modules::history::Removal;
+use crate::modules::store::StoreError;
+use crate::modules::themes::ThemeName;
+use crate::modules::types::{TimezoneEntry, MAX_RINGS};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Language {
+    #[default]
+    En,
+    PtBr,
+    De,
+    Ja,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Msg {
+    Title,
+    Undo,
+    UndoShortcut,
+    RedoShortcut,
+    Workspace,
+    NewWorkspace,
+    DuplicateWorkspace,
+    RenameWorkspace,
+    DeleteWorkspace,
+    ConfirmDeleteWorkspace,
+    CopyOf,
+    Config,
+    RingN,
+    YouAreHere,
+    Work,
+    AddZone,
+    AnchorDialTo,
+    OuterRing,
+    Utc,
+    BrowserLocal,
+    Language,
+    ExportJson,
+    ImportJson,
+    Dismiss,
+    Merge,
+    Replace,
+    Cancel,
+    Save,
+    MergeHint,
+    ReplaceHint,
+    ImportFailed,
+    ImportSummary,
+    OverlapWindow,
+    NoOverlapHours,
+    InTimezone,
+    MeetingsInOverlap,
+    MeetingsOutsideOverlap,
+    AllInWorkingHours,
+    OutsideWorkingHours,
+    Essential,
+    EssentialQuestion,
+    EditMeeting,
+    MeetingTitle,
+    Organizer,
+    AddMeeting,
+    Now,
+    NowAt,
+    DialLabel,
+    WorkingHours,
+    OutsideHours,
+    MeetingsAtHour,
+    EssentialTag,
+    Removed,
+    DeletedMeeting,
+    DeletedMeetings,
+    StorageUnavailable,
+    StorageFull,
+    StorageFailed,
+    CustomizeTheme,
+    ThemeNameLabel,
+    StartFrom,
+    EditingLight,
+    EditingDark,
+    LowContrast,
+    ContrastOk,
+    Delete,
+    NewThemeName,
+    ConfirmDeleteTheme,
+    ModeLight,
+    ModeDark,
+    ModeSystem,
+    ModeSunset,
+    ModeSystemHint,
+    SunsetToday,
+    SunNeverSets,
+    SunNeverRises,
+    HatchWorkingHours,
+    LayoutDial,
+    LayoutTimeline,
+    TimelineLabel,
+    LayoutWeek,
+    WeekLabel,
+    PreviousWeek,
+    ThisWeek,
+    NextWeek,
+    WeekCell,
+    ClocksChangeThisWeek,
+    UpcomingClockChanges,
+    ClockChangeIn,
+    ClockChangeToday,
+    MeetingShift,
+    JoinsOverlap,
+    LeavesOverlap,
+    YearOverlapTitle,
+    YearOverlapChart,
+    PreviousYear,
+    NextYear,
+    YearOverlapAverage,
+    WorstPeriods,
+    OverlapSteady,
+    ReorderRing,
+    RingColorsPreview,
+    SkippedHour,
+    RepeatedHour,
+    WorkingHoursIn,
+    OutsideHoursIn,
+    ShortHours,
+    ShortMinutes,
+    ImportInvalidJson,
+    ImportNotAnObject,
+    ImportMissingFormat,
+    ImportWrongFormat,
+    ImportUnsupportedVersion,
+    ImportMissing,
+    ImportEmpty,
+    ImportNoWorkspaces,
+    ImportExpectedString,
+    ImportExpectedArray,
+    ImportExpectedObject,
+    ImportExpectedBool,
+    ImportExpectedZone,
+    ImportUnknownMode,
+    ImportUnknownTheme,
+    ImportUnknownReference,
+    ImportUnknownZone,
+    ImportDuplicateZone,
+    ImportZoneCount,
+    ImportNotInWorkspace,
+    ImportHourOutOfRange,
+    ImportEndNotAfterStart,
+    ImportNotAColorSlot,
+    ImportNotAUtcHour,
+    ImportNotAnId,
+    ImportUnknownColorKey,
+    ImportNotAColor,
+    ImportDuplicateMeetingId,
+}
+
+/// Counted nouns, e.g. "1 meeting" / "2 meetings"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Hours,
+    Meetings,
+    Workspaces,
+    Zones,
+    Problems,
+    /// Counted after "in" ("in 9 days"), hence the German dative
+    Days,
+}
+
+impl Language {
+    pub const ALL: [Language; 4] = [Language::En, Language::PtBr, Language::De, Language::Ja];
+
+    /// BCP 47 tag, also used for `Intl` formatting and `<html lang>`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::PtBr => "pt-BR",
+            Language::De => "de",
+            Language::Ja => "ja",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|l| l.code() == code)
+    }
+
+    /// Best match for a browser language tag such as "de-AT" or "pt-PT"
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        let primary = tag.split(['-', '_']).next()?.to_ascii_lowercase();
+        match primary.as_str() {
+            "en" => Some(Language::En),
+            "pt" => Some(Language::PtBr),
+            "de" => Some(Language::De),
+            "ja" => Some(Language::Ja),
+            _ => None,
+        }
+    }
+
+    /// Name of the language in itself, for the picker
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::PtBr => "Português (Brasil)",
+            Language::De => "Deutsch",
+            Language::Ja => "日本語",
+        }
+    }
+
+    pub fn t(&self, msg: Msg) -> &'static str {
+        match self {
+            Language::En => en(msg),
+            Language::PtBr => pt_br(msg),
+            Language::De => de(msg),
+            Language::Ja => ja(msg),
+        }
+    }
+
+    /// `msg` with its `{}` placeholders replaced by `args` in order
+    pub fn fill(&self, msg: Msg, args: &[&str]) -> String {
+        let mut parts = self.t(msg).split("{}");
+        let mut out = parts.next().unwrap_or_default().to_string();
+        for (i, part) in parts.enumerate() {
+            out.push_str(args.get(i).copied().unwrap_or_default());
+            out.push_str(part);
+        }
+        out
+    }
+
+    /// "1 meeting", "2 meetings", following the language's plural rules
+    pub fn count(&self, noun: Count, n: usize) -> String {
+        let (one, other) = match self {
+            Language::En => en_count(noun),
+            Language::PtBr => pt_br_count(noun),
+            Language::De => de_count(noun),
+            Language::Ja => ja_count(noun),
+        };
+        // CLDR: Portuguese treats 0 like 1; Japanese has no plural forms
+        let is_one = match self {
+            Language::En | Language::De => n == 1,
+            Language::PtBr => n <= 1,
+            Language::Ja => false,
+        };
+        (if is_one { one } else { other }).replace("{}", &n.to_string())
+    }
+
+    /// Display name of a zone; untranslated names use the English name
+    pub fn zone_name(&self, tz: &TimezoneEntry) -> &'static str {
+        let translated = ZONE_NAMES.iter().find(|(id, ..)| *id == tz.id).map(|(_, pt, de, ja)| match self {
+            Language::En => "",
+            Language::PtBr => pt,
+            Language::De => de,
+            Language::Ja => ja,
+        });
+        match translated {
+            Some(name) if !name.is_empty() => name,
+            _ => tz.name,
+        }
+    }
+
+    /// Toast text for a destructive edit
+    pub fn removal(&self, removal: &Removal) -> String {
+        match removal {
+            Removal::Zones(zones) => {
+                let names: Vec<&str> = zones.iter().map(|tz| self.zone_name(tz)).collect();
+                self.fill(Msg::Removed, &[&names.join(", ")])
+            }
+            Removal::Meeting(title) => self.fill(Msg::DeletedMeeting, &[title]),
+            Removal::Meetings(n) => self.fill(Msg::DeletedMeetings, &[&self.count(Count::Meetings, *n)]),
+        }
+    }
+
+    pub fn store_error(&self, error: &StoreError) -> String {
+        match error {
+            StoreError::Unavailable => self.t(Msg::StorageUnavailable).to_string(),
+            StoreError::QuotaExceeded => self.t(Msg::StorageFull).to_string(),
+            StoreError::Failed(reason) => self.fill(Msg::StorageFailed, &[reason]),
+        }
+    }
+
+    /// One import problem, prefixed with where it is in the file
+    pub fn import_error(&self, error: &ImportError) -> String {
+        let message = match &error.problem {
+            ImportProblem::InvalidJson(reason) => self.fill(Msg::ImportInvalidJson, &[reason]),
+            ImportProblem::NotAnObject => self.t(Msg::ImportNotAnObject).to_string(),
+            ImportProblem::MissingFormat(expected) => self.fill(Msg::ImportMissingFormat, &[expected]),
+            ImportProblem::WrongFormat { expected, got } => self.fill(Msg::ImportWrongFormat, &[expected, got]),
+            ImportProblem::UnsupportedVersion { got, supported } => {
+                self.fill(Msg::ImportUnsupportedVersion, &[got, &supported.to_string()])
+            }
+            ImportProblem::Missing => self.t(Msg::ImportMissing).to_string(),
+            ImportProblem::Empty => self.t(Msg::ImportEmpty).to_string(),
+            ImportProblem::NoWorkspaces => self.t(Msg::ImportNoWorkspaces).to_string(),
+            ImportProblem::ExpectedString(got) => self.fill(Msg::ImportExpectedString, &[got]),
+            ImportProblem::ExpectedArray(got) => self.fill(Msg::ImportExpectedArray, &[got]),
+            ImportProblem::ExpectedObject(got) => self.fill(Msg::ImportExpectedObject, &[got]),
+            ImportProblem::ExpectedBool(got) => self.fill(Msg::ImportExpectedBool, &[got]),
+            ImportProblem::ExpectedZone(got) => self.fill(Msg::ImportExpectedZone, &[got]),
+            ImportProblem::UnknownMode(got) => self.fill(Msg::ImportUnknownMode, &[got]),
+            ImportProblem::UnknownTheme(got) => {
+                let known: Vec<&str> = ThemeName::ALL.iter().map(|t| t.label()).collect();
+                self.fill(Msg::ImportUnknownTheme, &[got, &known.join(", ")])
+            }
+            ImportProblem::UnknownReference(got) => self.fill(Msg::ImportUnknownReference, &[got]),
+            ImportProblem::UnknownZone(id) => self.fill(Msg::ImportUnknownZone, &[id]),
+            ImportProblem::DuplicateZone(id) => self.fill(Msg::ImportDuplicateZone, &[id]),
+            ImportProblem::ZoneCount(found) => {
+                self.fill(Msg::ImportZoneCount, &[&MAX_RINGS.to_string(), &found.to_string()])
+            }
+            ImportProblem::NotInWorkspace(id) => self.fill(Msg::ImportNotInWorkspace, &[id]),
+            ImportProblem::HourOutOfRange { min, max, got } => {
+                self.fill(Msg::ImportHourOutOfRange, &[&min.to_string(), &max.to_string(), got])
+            }
+            ImportProblem::EndNotAfterStart { start, end } => {
+                self.fill(Msg::ImportEndNotAfterStart, &[&start.to_string(), &end.to_string()])
+            }
+            ImportProblem::NotAColorSlot(got) => {
+                self.fill(Msg::ImportNotAColorSlot, &[&(MAX_RINGS - 1).to_string(), got])
+            }
+            ImportProblem::NotAUtcHour(got) => self.fill(Msg::ImportNotAUtcHour, &[got]),
+            ImportProblem::NotAnId(got) => self.fill(Msg::ImportNotAnId, &[got]),
+            ImportProblem::DuplicateMeetingId(id) => self.fill(Msg::ImportDuplicateMeetingId, &[&id.to_string()]),
+            ImportProblem::UnknownColorKey => self.t(Msg::ImportUnknownColorKey).to_string(),
+            ImportProblem::NotAColor(got) => self.fill(Msg::ImportNotAColor, &[got]),
+        };
+        if error.path.is_empty() {
+            message
+        } else {
+            format!("{}: {}", error.path, message)
+        }
+    }
+
+    /// `value` with `digits` decimals and the language's decimal separator
+    pub fn decimal(&self, value: f64, digits: usize) -> String {
+        let text = format!("{:.*}", digits, value);
+        match self {
+            Language::PtBr | Language::De => text.replace('.', ","),
+            Language::En | Language::Ja => text,
+        }
+    }
+
+    /// An amount of hours in the short form used by charts, e.g. "4.5h"
+    pub fn short_hours(&self, hours: f64, digits: usize) -> String {
+        self.fill(Msg::ShortHours, &[&self.decimal(hours, digits)])
+    }
+
+    /// A clock change as people say it, e.g. "+1h" or "-30m"
+    pub fn shift(&self, hours: f64) -> String {
+        if hours.fract() == 0.0 {
+            self.fill(Msg::ShortHours, &[&format!("{:+}", hours as i64)])
+        } else {
+            self.fill(Msg::ShortMinutes, &[&format!("{:+}", (hours * 60.0).round() as i64)])
+        }
+    }
+}
+
+fn en(msg: Msg) -> &'static str {
+    match msg {
+        Msg::Title => "Timezone Meeting Clock",
+        Msg::Undo => "Undo",
+        Msg::UndoShortcut => "Undo (Ctrl+Z)",
+        Msg::RedoShortcut => "Redo (Ctrl+Shift+Z)",
+        Msg::Workspace => "Workspace",
+        Msg::NewWorkspace => "New workspace",
+        Msg::DuplicateWorkspace => "Duplicate workspace",
+        Msg::RenameWorkspace => "Rename workspace",
+        Msg::DeleteWorkspace => "Delete workspace",
+        Msg::ConfirmDeleteWorkspace => "Delete workspace \"{}\" and its meetings?",
+        Msg::CopyOf => "{} copy",
+        Msg::Config => "Config",
+        Msg::RingN => "Ring {}",
+        Msg::YouAreHere => "you are here",
+        Msg::Work => "Work",
+        Msg::AddZone => "+ Add",
+        Msg::AnchorDialTo => "Anchor dial to",
+        Msg::OuterRing => "Outer ring",
+        Msg::Utc => "UTC",
+        Msg::BrowserLocal => "Browser local time",
+        Msg::Language => "Language",
+        Msg::ExportJson => "Export JSON",
+        Msg::ImportJson => "Import JSON",
+        Msg::Dismiss => "Dismiss",
+        Msg::Merge => "Merge",
+        Msg::Replace => "Replace",
+        Msg::Cancel => "Cancel",
+        Msg::Save => "Save",
+        Msg::MergeHint => "Add to existing workspaces; same-named workspaces gain the missing zones and meetings",
+        Msg::ReplaceHint => "Discard all current workspaces",
+        Msg::ImportFailed => "Import failed: {}",
+        Msg::ImportSummary => "Import {} with {} and {}?",
+        Msg::OverlapWindow => "Overlap Window: {}",
+        Msg::NoOverlapHours => "No overlap hours found",
+        Msg::InTimezone => "{} ({} timezone)",
+        Msg::MeetingsInOverlap => "{} in overlap window",
+        Msg::MeetingsOutsideOverlap => "{} outside overlap",
+        Msg::AllInWorkingHours => "\u{2713} All timezones in working hours \u{2014} ideal!",
+        Msg::OutsideWorkingHours => "\u{26A0} {} outside working hours",
+        Msg::Essential => "Essential",
+        Msg::EssentialQuestion => "Essential for cross-timezone collaboration?",
+        Msg::EditMeeting => "Edit meeting",
+        Msg::MeetingTitle => "Meeting title",
+        Msg::Organizer => "Organizer",
+        Msg::AddMeeting => "+ Add Meeting at This Time",
+        Msg::Now => "NOW",
+        Msg::NowAt => "Now: {}",
+        Msg::DialLabel => "Meeting clock. Arrow keys move between hours and rings, Enter selects an hour.",
+        Msg::WorkingHours => "working hours",
+        Msg::OutsideHours => "outside working hours",
+        Msg::MeetingsAtHour => "{} at this hour",
+        Msg::EssentialTag => "(essential)",
+        Msg::Removed => "Removed {}",
+        Msg::DeletedMeeting => "Deleted \"{}\"",
+        Msg::DeletedMeetings => "Deleted {}",
+        Msg::StorageUnavailable => "Browser storage is disabled (e.g. private browsing), so changes will be lost when this tab closes.",
+        Msg::StorageFull => "Browser storage is full, so recent changes were not saved.",
+        Msg::StorageFailed => "Settings could not be saved: {}",
+        Msg::CustomizeTheme => "Customize theme",
+        Msg::ThemeNameLabel => "Theme name",
+        Msg::StartFrom => "Start from",
+        Msg::EditingLight => "Editing light-mode colors; switch to dark mode to edit the other palette.",
+        Msg::EditingDark => "Editing dark-mode colors; switch to light mode to edit the other palette.",
+        Msg::LowContrast => "{} on {}: {}:1, needs {}:1",
+        Msg::ContrastOk => "All checked text meets the WCAG AA contrast minimum.",
+        Msg::Delete => "Delete",
+        Msg::NewThemeName => "My theme",
+        Msg::ConfirmDeleteTheme => "Delete theme \"{}\"?",
+        Msg::ModeLight => "Light",
+        Msg::ModeDark => "Dark",
+        Msg::ModeSystem => "System",
+        Msg::ModeSunset => "Sunset",
+        Msg::ModeSystemHint => "Follow the operating system's light/dark setting",
+        Msg::SunsetToday => "Dark from sunset ({}) to sunrise ({}), {} time",
+        Msg::SunNeverSets => "The sun does not set today in {}, so it stays light",
+        Msg::SunNeverRises => "The sun does not rise today in {}, so it stays dark",
+        Msg::HatchWorkingHours => "Stripe working hours",
+        Msg::LayoutDial => "Dial",
+        Msg::LayoutTimeline => "Timeline",
+        Msg::TimelineLabel => "Meeting timeline. Arrow keys move between hours and zones, Enter selects an hour.",
+        Msg::LayoutWeek => "Week",
+        Msg::WeekLabel => "Overlap across the week",
+        Msg::PreviousWeek => "Previous week",
+        Msg::ThisWeek => "This week",
+        Msg::NextWeek => "Next week",
+        Msg::WeekCell => "{} of {} zones working",
+        Msg::ClocksChangeThisWeek => "Clocks change this week: {}",
+        Msg::UpcomingClockChanges => "Upcoming clock changes",
+        Msg::ClockChangeIn => "{} moves {} in {}",
+        Msg::ClockChangeToday => "{} moves {} today",
+        Msg::MeetingShift => "{} at {} UTC will be {} local",
+        Msg::JoinsOverlap => "now inside everyone's working hours",
+        Msg::LeavesOverlap => "no longer inside everyone's working hours",
+        Msg::YearOverlapTitle => "Overlap over the year",
+        Msg::YearOverlapChart => "Fully overlapping hours per day",
+        Msg::PreviousYear => "Previous year",
+        Msg::NextYear => "Next year",
+        Msg::YearOverlapAverage => "{} of full overlap on an average weekday",
+        Msg::WorstPeriods => "Lowest overlap ({} per weekday): {}",
+        Msg::OverlapSteady => "The overlap is the same all year",
+        Msg::ReorderRing => "Drag to reorder, or use the arrow keys",
+        Msg::RingColorsPreview => "Rings after the fifth mix two neighbouring ring colors:",
+        Msg::SkippedHour => "skipped as clocks go forward",
+        Msg::RepeatedHour => "happens twice as clocks go back",
+        Msg::WorkingHoursIn => "working hours in {}",
+        Msg::OutsideHoursIn => "outside working hours in {}",
+        Msg::ShortHours => "{}h",
+        Msg::ShortMinutes => "{}m",
+        Msg::ImportInvalidJson => "not valid JSON ({})",
+        Msg::ImportNotAnObject => "expected a JSON object at the top level",
+        Msg::ImportMissingFormat => "missing; expected \"{}\"",
+        Msg::ImportWrongFormat => "expected \"{}\", got {}",
+        Msg::ImportUnsupportedVersion => "unsupported version {}; this app reads version {}",
+        Msg::ImportMissing => "missing",
+        Msg::ImportEmpty => "must not be empty",
+        Msg::ImportNoWorkspaces => "must contain at least one workspace",
+        Msg::ImportExpectedString => "expected a string, got {}",
+        Msg::ImportExpectedArray => "expected an array, got {}",
+        Msg::ImportExpectedObject => "expected an object, got {}",
+        Msg::ImportExpectedBool => "expected true or false, got {}",
+        Msg::ImportExpectedZone => "expected an object like {\"id\": \"europe_london\"}, got {}",
+        Msg::ImportUnknownMode => "expected \"Light\", \"Dark\", \"System\" or \"Sunset\", got \"{}\"",
+        Msg::ImportUnknownTheme => "unknown theme \"{}\" (expected one of {})",
+        Msg::ImportUnknownReference => "expected \"first\", \"utc\", \"local\" or a zone id, got \"{}\"",
+        Msg::ImportUnknownZone => "unknown zone id \"{}\"",
+        Msg::ImportDuplicateZone => "duplicate zone \"{}\"",
+        Msg::ImportZoneCount => "must list 1 to {} zones, found {}",
+        Msg::ImportNotInWorkspace => "\"{}\" is not one of this workspace's zones",
+        Msg::ImportHourOutOfRange => "expected an hour between {} and {}, got {}",
+        Msg::ImportEndNotAfterStart => "must be after work_start ({} >= {})",
+        Msg::ImportNotAColorSlot => "expected a palette slot from 0 to {}, got {}",
+        Msg::ImportNotAUtcHour => "expected a whole hour from 0 to 23, got {}",
+        Msg::ImportNotAnId => "expected a positive whole number, got {}",
+        Msg::ImportUnknownColorKey => "unknown color key",
+        Msg::ImportNotAColor => "expected a color like \"#1a2b3c\", got {}",
+        Msg::ImportDuplicateMeetingId => "duplicate meeting id {}",
+    }
+}
+
+fn en_count(noun: Count) -> (&'static str, &'static str) {
+    match noun {
+        Count::Hours => ("{} hour", "{} hours"),
+        Count::Meetings => ("{} meeting", "{} meetings"),
+        Count::Workspaces => ("{} workspace", "{} workspaces"),
+        Count::Zones => ("{} zone", "{} zones"),
+        Count::Problems => ("{} problem", "{} problems"),
+        Count::Days => ("{} day", "{} days"),
+    }
+}
+
+fn pt_br(msg: Msg) -> &'static str {
+    match msg {
+        Msg::Title => "Relógio de Reuniões por Fuso Horário",
+        Msg::Undo => "Desfazer",
+        Msg::UndoShortcut => "Desfazer (Ctrl+Z)",
+        Msg::RedoShortcut => "Refazer (Ctrl+Shift+Z)",
+        Msg::Workspace => "Espaço de trabalho",
+        Msg::NewWorkspace => "Novo espaço de trabalho",
+        Msg::DuplicateWorkspace => "Duplicar espaço de trabalho",
+        Msg::RenameWorkspace => "Renomear espaço de trabalho",
+        Msg::DeleteWorkspace => "Excluir espaço de trabalho",
+        Msg::ConfirmDeleteWorkspace => "Excluir o espaço de trabalho \"{}\" e suas reuniões?",
+        Msg::CopyOf => "{} (cópia)",
+        Msg::Config => "Configurações",
+        Msg::RingN => "Anel {}",
+        Msg::YouAreHere => "você está aqui",
+        Msg::Work => "Expediente",
+        Msg::AddZone => "+ Adicionar",
+        Msg::AnchorDialTo => "Ancorar mostrador em",
+        Msg::OuterRing => "Anel externo",
+        Msg::Utc => "UTC",
+        Msg::BrowserLocal => "Hora local do navegador",
+        Msg::Language => "Idioma",
+        Msg::ExportJson => "Exportar JSON",
+        Msg::ImportJson => "Importar JSON",
+        Msg::Dismiss => "Fechar",
+        Msg::Merge => "Mesclar",
+        Msg::Replace => "Substituir",
+        Msg::Cancel => "Cancelar",
+        Msg::Save => "Salvar",
+        Msg::MergeHint => "Adicionar aos espaços existentes; espaços com o mesmo nome recebem os fusos e reuniões que faltam",
+        Msg::ReplaceHint => "Descartar todos os espaços de trabalho atuais",
+        Msg::ImportFailed => "Falha na importação: {}",
+        Msg::ImportSummary => "Importar {} com {} e {}?",
+        Msg::OverlapWindow => "Janela de sobreposição: {}",
+        Msg::NoOverlapHours => "Nenhum horário de sobreposição encontrado",
+        Msg::InTimezone => "{} (fuso {})",
+        Msg::MeetingsInOverlap => "{} na janela de sobreposição",
+        Msg::MeetingsOutsideOverlap => "{} fora da sobreposição",
+        Msg::AllInWorkingHours => "\u{2713} Todos os fusos em horário de trabalho \u{2014} ideal!",
+        Msg::OutsideWorkingHours => "\u{26A0} {} fora do horário de trabalho",
+        Msg::Essential => "Essencial",
+        Msg::EssentialQuestion => "Essencial para a colaboração entre fusos?",
+        Msg::EditMeeting => "Editar reunião",
+        Msg::MeetingTitle => "Título da reunião",
+        Msg::Organizer => "Organizador",
+        Msg::AddMeeting => "+ Adicionar reunião neste horário",
+        Msg::Now => "AGORA",
+        Msg::NowAt => "Agora: {}",
+        Msg::DialLabel => "Relógio de reuniões. As setas movem entre horas e anéis; Enter seleciona uma hora.",
+        Msg::WorkingHours => "horário de trabalho",
+        Msg::OutsideHours => "fora do horário de trabalho",
+        Msg::MeetingsAtHour => "{} neste horário",
+        Msg::EssentialTag => "(essencial)",
+        Msg::Removed => "Removido: {}",
+        Msg::DeletedMeeting => "\"{}\" excluída",
+        Msg::DeletedMeetings => "Foram excluídas {}",
+        Msg::StorageUnavailable => "O armazenamento do navegador está desativado (por exemplo, navegação privada), então as alterações serão perdidas ao fechar esta aba.",
+        Msg::StorageFull => "O armazenamento do navegador está cheio, então as alterações recentes não foram salvas.",
+        Msg::StorageFailed => "Não foi possível salvar as configurações: {}",
+        Msg::CustomizeTheme => "Personalizar tema",
+        Msg::ThemeNameLabel => "Nome do tema",
+        Msg::StartFrom => "Começar de",
+        Msg::EditingLight => "Editando as cores do modo claro; mude para o modo escuro para editar a outra paleta.",
+        Msg::EditingDark => "Editando as cores do modo escuro; mude para o modo claro para editar a outra paleta.",
+        Msg::LowContrast => "{} sobre {}: {}:1, mínimo {}:1",
+        Msg::ContrastOk => "Todo o texto verificado atende ao contraste mínimo WCAG AA.",
+        Msg::Delete => "Excluir",
+        Msg::NewThemeName => "Meu tema",
+        Msg::ConfirmDeleteTheme => "Excluir o tema \"{}\"?",
+        Msg::ModeLight => "Claro",
+        Msg::ModeDark => "Escuro",
+        Msg::ModeSystem => "Sistema",
+        Msg::ModeSunset => "Pôr do sol",
+        Msg::ModeSystemHint => "Seguir a configuração clara/escura do sistema operacional",
+        Msg::SunsetToday => "Escuro do pôr do sol ({}) ao nascer do sol ({}), horário de {}",
+        Msg::SunNeverSets => "O sol não se põe hoje em {}, então fica claro",
+        Msg::SunNeverRises => "O sol não nasce hoje em {}, então fica escuro",
+        Msg::HatchWorkingHours => "Listrar horário de trabalho",
+        Msg::LayoutDial => "Mostrador",
+        Msg::LayoutTimeline => "Linha do tempo",
+        Msg::TimelineLabel => "Linha do tempo de reuniões. As setas movem entre horas e fusos; Enter seleciona uma hora.",
+        Msg::LayoutWeek => "Semana",
+        Msg::WeekLabel => "Sobreposição ao longo da semana",
+        Msg::PreviousWeek => "Semana anterior",
+        Msg::ThisWeek => "Esta semana",
+        Msg::NextWeek => "Próxima semana",
+        Msg::WeekCell => "{} de {} fusos trabalhando",
+        Msg::ClocksChangeThisWeek => "Os relógios mudam nesta semana: {}",
+        Msg::UpcomingClockChanges => "Próximas mudanças de horário",
+        Msg::ClockChangeIn => "{} muda {} em {}",
+        Msg::ClockChangeToday => "{} muda {} hoje",
+        Msg::MeetingShift => "{} às {} UTC passará a ser {} no horário local",
+        Msg::JoinsOverlap => "passa a estar no horário de trabalho de todos",
+        Msg::LeavesOverlap => "deixa de estar no horário de trabalho de todos",
+        Msg::YearOverlapTitle => "Sobreposição ao longo do ano",
+        Msg::YearOverlapChart => "Horas de sobreposição total por dia",
+        Msg::PreviousYear => "Ano anterior",
+        Msg::NextYear => "Próximo ano",
+        Msg::YearOverlapAverage => "{} de sobreposição total num dia útil médio",
+        Msg::WorstPeriods => "Menor sobreposição ({} por dia útil): {}",
+        Msg::OverlapSteady => "A sobreposição é a mesma o ano todo",
+        Msg::ReorderRing => "Arraste para reordenar ou use as setas",
+        Msg::RingColorsPreview => "Os anéis após o quinto misturam duas cores de anel vizinhas:",
+        Msg::SkippedHour => "pulada com o adiantamento do relógio",
+        Msg::RepeatedHour => "acontece duas vezes com o atraso do relógio",
+        Msg::WorkingHoursIn => "horário de trabalho em {}",
+        Msg::OutsideHoursIn => "fora do horário de trabalho em {}",
+        Msg::ShortHours => "{}h",
+        Msg::ShortMinutes => "{}min",
+        Msg::ImportInvalidJson => "JSON inválido ({})",
+        Msg::ImportNotAnObject => "esperava um objeto JSON no nível superior",
+        Msg::ImportMissingFormat => "ausente; esperava \"{}\"",
+        Msg::ImportWrongFormat => "esperava \"{}\", recebeu {}",
+        Msg::ImportUnsupportedVersion => "versão {} não suportada; este app lê a versão {}",
+        Msg::ImportMissing => "ausente",
+        Msg::ImportEmpty => "não pode estar vazio",
+        Msg::ImportNoWorkspaces => "deve conter pelo menos um espaço de trabalho",
+        Msg::ImportExpectedString => "esperava um texto, recebeu {}",
+        Msg::ImportExpectedArray => "esperava uma lista, recebeu {}",
+        Msg::ImportExpectedObject => "esperava um objeto, recebeu {}",
+        Msg::ImportExpectedBool => "esperava true ou false, recebeu {}",
+        Msg::ImportExpectedZone => "esperava um objeto como {\"id\": \"europe_london\"}, recebeu {}",
+        Msg::ImportUnknownMode => "esperava \"Light\", \"Dark\", \"System\" ou \"Sunset\", recebeu \"{}\"",
+        Msg::ImportUnknownTheme => "tema desconhecido \"{}\" (esperava um de {})",
+        Msg::ImportUnknownReference => "esperava \"first\", \"utc\", \"local\" ou um id de fuso, recebeu \"{}\"",
+        Msg::ImportUnknownZone => "id de fuso desconhecido \"{}\"",
+        Msg::ImportDuplicateZone => "fuso duplicado \"{}\"",
+        Msg::ImportZoneCount => "deve listar de 1 a {} fusos, encontrou {}",
+        Msg::ImportNotInWorkspace => "\"{}\" não é um dos fusos deste espaço de trabalho",
+        Msg::ImportHourOutOfRange => "esperava uma hora entre {} e {}, recebeu {}",
+        Msg::ImportEndNotAfterStart => "deve ser depois de work_start ({} >= {})",
+        Msg::ImportNotAColorSlot => "esperava um número de cor de 0 a {}, recebeu {}",
+        Msg::ImportNotAUtcHour => "esperava uma hora inteira de 0 a 23, recebeu {}",
+        Msg::ImportNotAnId => "esperava um número inteiro positivo, recebeu {}",
+        Msg::ImportUnknownColorKey => "chave de cor desconhecida",
+        Msg::ImportNotAColor => "esperava uma cor como \"#1a2b3c\", recebeu {}",
+        Msg::ImportDuplicateMeetingId => "id de reunião duplicado {}",
+    }
+}
+
+fn pt_br_count(noun: Count) -> (&'static str, &'static str) {
+    match noun {
+        Count::Hours => ("{} hora", "{} horas"),
+        Count::Meetings => ("{} reunião", "{} reuniões"),
+        Count::Workspaces => ("{} espaço de trabalho", "{} espaços de trabalho"),
+        Count::Zones => ("{} fuso", "{} fusos"),
+        Count::Problems => ("{} problema", "{} problemas"),
+        Count::Days => ("{} dia", "{} dias"),
+    }
+}
+
+fn de(msg: Msg) -> &'static str {
+    match msg {
+        Msg::Title => "Zeitzonen-Meeting-Uhr",
+        Msg::Undo => "Rückgängig",
+        Msg::UndoShortcut => "Rückgängig (Strg+Z)",
+        Msg::RedoShortcut => "Wiederholen (Strg+Umschalt+Z)",
+        Msg::Workspace => "Arbeitsbereich",
+        Msg::NewWorkspace => "Neuer Arbeitsbereich",
+        Msg::DuplicateWorkspace => "Arbeitsbereich duplizieren",
+        Msg::RenameWorkspace => "Arbeitsbereich umbenennen",
+        Msg::DeleteWorkspace => "Arbeitsbereich löschen",
+        Msg::ConfirmDeleteWorkspace => "Arbeitsbereich \u{201E}{}\u{201C} und seine Meetings löschen?",
+        Msg::CopyOf => "{} (Kopie)",
+        Msg::Config => "Einstellungen",
+        Msg::RingN => "Ring {}",
+        Msg::YouAreHere => "Sie sind hier",
+        Msg::Work => "Arbeitszeit",
+        Msg::AddZone => "+ Hinzufügen",
+        Msg::AnchorDialTo => "Zifferblatt ausrichten an",
+        Msg::OuterRing => "Äußerer Ring",
+        Msg::Utc => "UTC",
+        Msg::BrowserLocal => "Lokale Browserzeit",
+        Msg::Language => "Sprache",
+        Msg::ExportJson => "JSON exportieren",
+        Msg::ImportJson => "JSON importieren",
+        Msg::Dismiss => "Schließen",
+        Msg::Merge => "Zusammenführen",
+        Msg::Replace => "Ersetzen",
+        Msg::Cancel => "Abbrechen",
+        Msg::Save => "Speichern",
+        Msg::MergeHint => "Zu bestehenden Arbeitsbereichen hinzufügen; gleichnamige erhalten die fehlenden Zonen und Meetings",
+        Msg::ReplaceHint => "Alle aktuellen Arbeitsbereiche verwerfen",
+        Msg::ImportFailed => "Import fehlgeschlagen: {}",
+        Msg::ImportSummary => "{} mit {} und {} importieren?",
+        Msg::OverlapWindow => "Überschneidungsfenster: {}",
+        Msg::NoOverlapHours => "Keine überschneidenden Stunden gefunden",
+        Msg::InTimezone => "{} (Zeitzone {})",
+        Msg::MeetingsInOverlap => "{} im Überschneidungsfenster",
+        Msg::MeetingsOutsideOverlap => "{} außerhalb der Überschneidung",
+        Msg::AllInWorkingHours => "\u{2713} Alle Zeitzonen in der Arbeitszeit \u{2014} ideal!",
+        Msg::OutsideWorkingHours => "\u{26A0} {} außerhalb der Arbeitszeit",
+        Msg::Essential => "Wichtig",
+        Msg::EssentialQuestion => "Wichtig für die Zusammenarbeit über Zeitzonen hinweg?",
+        Msg::EditMeeting => "Meeting bearbeiten",
+        Msg::MeetingTitle => "Meeting-Titel",
+        Msg::Organizer => "Organisator",
+        Msg::AddMeeting => "+ Meeting zu dieser Zeit hinzufügen",
+        Msg::Now => "JETZT",
+        Msg::NowAt => "Jetzt: {}",
+        Msg::DialLabel => "Meeting-Uhr. Pfeiltasten wechseln zwischen Stunden und Ringen, Eingabe wählt eine Stunde.",
+        Msg::WorkingHours => "Arbeitszeit",
+        Msg::OutsideHours => "außerhalb der Arbeitszeit",
+        Msg::MeetingsAtHour => "{} zu dieser Stunde",
+        Msg::EssentialTag => "(wichtig)",
+        Msg::Removed => "Entfernt: {}",
+        Msg::DeletedMeeting => "\u{201E}{}\u{201C} gelöscht",
+        Msg::DeletedMeetings => "{} gelöscht",
+        Msg::StorageUnavailable => "Der Browserspeicher ist deaktiviert (z. B. im privaten Modus), daher gehen Änderungen beim Schließen dieses Tabs verloren.",
+        Msg::StorageFull => "Der Browserspeicher ist voll, daher wurden die letzten Änderungen nicht gespeichert.",
+        Msg::StorageFailed => "Einstellungen konnten nicht gespeichert werden: {}",
+        Msg::CustomizeTheme => "Design anpassen",
+        Msg::ThemeNameLabel => "Name des Designs",
+        Msg::StartFrom => "Ausgehend von",
+        Msg::EditingLight => "Farben des hellen Modus werden bearbeitet; wechseln Sie in den dunklen Modus, um die andere Palette zu bearbeiten.",
+        Msg::EditingDark => "Farben des dunklen Modus werden bearbeitet; wechseln Sie in den hellen Modus, um die andere Palette zu bearbeiten.",
+        Msg::LowContrast => "{} auf {}: {}:1, mindestens {}:1",
+        Msg::ContrastOk => "Alle geprüften Texte erfüllen den WCAG-AA-Mindestkontrast.",
+        Msg::Delete => "Löschen",
+        Msg::NewThemeName => "Mein Design",
+        Msg::ConfirmDeleteTheme => "Design \u{201E}{}\u{201C} löschen?",
+        Msg::ModeLight => "Hell",
+        Msg::ModeDark => "Dunkel",
+        Msg::ModeSystem => "System",
+        Msg::ModeSunset => "Sonnenuntergang",
+        Msg::ModeSystemHint => "Der Hell-/Dunkel-Einstellung des Betriebssystems folgen",
+        Msg::SunsetToday => "Dunkel von Sonnenuntergang ({}) bis Sonnenaufgang ({}), Zeit in {}",
+        Msg::SunNeverSets => "Die Sonne geht heute in {} nicht unter, es bleibt hell",
+        Msg::SunNeverRises => "Die Sonne geht heute in {} nicht auf, es bleibt dunkel",
+        Msg::HatchWorkingHours => "Arbeitszeit schraffieren",
+        Msg::LayoutDial => "Zifferblatt",
+        Msg::LayoutTimeline => "Zeitleiste",
+        Msg::TimelineLabel => "Meeting-Zeitleiste. Pfeiltasten wechseln zwischen Stunden und Zonen, Eingabe wählt eine Stunde.",
+        Msg::LayoutWeek => "Woche",
+        Msg::WeekLabel => "Überschneidung über die Woche",
+        Msg::PreviousWeek => "Vorherige Woche",
+        Msg::ThisWeek => "Diese Woche",
+        Msg::NextWeek => "Nächste Woche",
+        Msg::WeekCell => "{} von {} Zonen arbeiten",
+        Msg::ClocksChangeThisWeek => "Zeitumstellung diese Woche: {}",
+        Msg::UpcomingClockChanges => "Bevorstehende Zeitumstellungen",
+        Msg::ClockChangeIn => "{}: Uhren {} in {}",
+        Msg::ClockChangeToday => "{}: Uhren {} heute",
+        Msg::MeetingShift => "{} um {} UTC ist dann {} Ortszeit",
+        Msg::JoinsOverlap => "liegt dann in allen Arbeitszeiten",
+        Msg::LeavesOverlap => "liegt dann nicht mehr in allen Arbeitszeiten",
+        Msg::YearOverlapTitle => "Überschneidung im Jahresverlauf",
+        Msg::YearOverlapChart => "Stunden voller Überschneidung pro Tag",
+        Msg::PreviousYear => "Vorheriges Jahr",
+        Msg::NextYear => "Nächstes Jahr",
+        Msg::YearOverlapAverage => "{} volle Überschneidung an einem durchschnittlichen Werktag",
+        Msg::WorstPeriods => "Geringste Überschneidung ({} pro Werktag): {}",
+        Msg::OverlapSteady => "Die Überschneidung ist das ganze Jahr gleich",
+        Msg::ReorderRing => "Zum Umsortieren ziehen oder Pfeiltasten verwenden",
+        Msg::RingColorsPreview => "Ringe nach dem fünften mischen zwei benachbarte Ringfarben:",
+        Msg::SkippedHour => "entfällt durch die Zeitumstellung",
+        Msg::RepeatedHour => "findet durch die Zeitumstellung zweimal statt",
+        Msg::WorkingHoursIn => "Arbeitszeit in {}",
+        Msg::OutsideHoursIn => "außerhalb der Arbeitszeit in {}",
+        Msg::ShortHours => "{} Std.",
+        Msg::ShortMinutes => "{} Min.",
+        Msg::ImportInvalidJson => "kein gültiges JSON ({})",
+        Msg::ImportNotAnObject => "auf oberster Ebene wird ein JSON-Objekt erwartet",
+        Msg::ImportMissingFormat => "fehlt; erwartet \"{}\"",
+        Msg::ImportWrongFormat => "erwartet \"{}\", erhalten {}",
+        Msg::ImportUnsupportedVersion => "Version {} wird nicht unterstützt; diese App liest Version {}",
+        Msg::ImportMissing => "fehlt",
+        Msg::ImportEmpty => "darf nicht leer sein",
+        Msg::ImportNoWorkspaces => "muss mindestens einen Arbeitsbereich enthalten",
+        Msg::ImportExpectedString => "Zeichenkette erwartet, erhalten {}",
+        Msg::ImportExpectedArray => "Liste erwartet, erhalten {}",
+        Msg::ImportExpectedObject => "Objekt erwartet, erhalten {}",
+        Msg::ImportExpectedBool => "true oder false erwartet, erhalten {}",
+        Msg::ImportExpectedZone => "Objekt wie {\"id\": \"europe_london\"} erwartet, erhalten {}",
+        Msg::ImportUnknownMode => "\"Light\", \"Dark\", \"System\" oder \"Sunset\" erwartet, erhalten \"{}\"",
+        Msg::ImportUnknownTheme => "unbekanntes Theme \"{}\" (erwartet eines von {})",
+        Msg::ImportUnknownReference => "\"first\", \"utc\", \"local\" oder eine Zonen-ID erwartet, erhalten \"{}\"",
+        Msg::ImportUnknownZone => "unbekannte Zonen-ID \"{}\"",
+        Msg::ImportDuplicateZone => "doppelte Zone \"{}\"",
+        Msg::ImportZoneCount => "muss 1 bis {} Zonen enthalten, gefunden {}",
+        Msg::ImportNotInWorkspace => "\"{}\" ist keine Zone dieses Arbeitsbereichs",
+        Msg::ImportHourOutOfRange => "Stunde zwischen {} und {} erwartet, erhalten {}",
+        Msg::ImportEndNotAfterStart => "muss nach work_start liegen ({} >= {})",
+        Msg::ImportNotAColorSlot => "Farbnummer von 0 bis {} erwartet, erhalten {}",
+        Msg::ImportNotAUtcHour => "volle Stunde von 0 bis 23 erwartet, erhalten {}",
+        Msg::ImportNotAnId => "positive ganze Zahl erwartet, erhalten {}",
+        Msg::ImportUnknownColorKey => "unbekannter Farbschlüssel",
+        Msg::ImportNotAColor => "Farbe wie \"#1a2b3c\" erwartet, erhalten {}",
+        Msg::ImportDuplicateMeetingId => "doppelte Meeting-ID {}",
+    }
+}
+
+fn de_count(noun: Count) -> (&'static str, &'static str) {
+    match noun {
+        Count::Hours => ("{} Stunde", "{} Stunden"),
+        Count::Meetings => ("{} Meeting", "{} Meetings"),
+        Count::Workspaces => ("{} Arbeitsbereich", "{} Arbeitsbereiche"),
+        Count::Zones => ("{} Zone", "{} Zonen"),
+        Count::Problems => ("{} Problem", "{} Probleme"),
+        Count::Days => ("{} Tag", "{} Tagen"),
+    }
+}
+
+fn ja(msg: Msg) -> &'static str {
+    match msg {
+        Msg::Title => "タイムゾーン会議時計",
+        Msg::Undo => "元に戻す",
+        Msg::UndoShortcut => "元に戻す (Ctrl+Z)",
+        Msg::RedoShortcut => "やり直す (Ctrl+Shift+Z)",
+        Msg::Workspace => "ワークスペース",
+        Msg::NewWorkspace => "新しいワークスペース",
+        Msg::DuplicateWorkspace => "ワークスペースを複製",
+        Msg::RenameWorkspace => "ワークスペース名を変更",
+        Msg::DeleteWorkspace => "ワークスペースを削除",
+        Msg::ConfirmDeleteWorkspace => "ワークスペース「{}」とその会議を削除しますか？",
+        Msg::CopyOf => "{} のコピー",
+        Msg::Config => "設定",
+        Msg::RingN => "リング {}",
+        Msg::YouAreHere => "現在地",
+        Msg::Work => "勤務時間",
+        Msg::AddZone => "+ 追加",
+        Msg::AnchorDialTo => "文字盤の基準",
+        Msg::OuterRing => "外側のリング",
+        Msg::Utc => "UTC",
+        Msg::BrowserLocal => "ブラウザの現地時刻",
+        Msg::Language => "言語",
+        Msg::ExportJson => "JSON をエクスポート",
+        Msg::ImportJson => "JSON をインポート",
+        Msg::Dismiss => "閉じる",
+        Msg::Merge => "統合",
+        Msg::Replace => "置き換え",
+        Msg::Cancel => "キャンセル",
+        Msg::Save => "保存",
+        Msg::MergeHint => "既存のワークスペースに追加します。同名のワークスペースには不足しているタイムゾーンと会議が追加されます",
+        Msg::ReplaceHint => "現在のワークスペースをすべて破棄します",
+        Msg::ImportFailed => "インポートに失敗しました: {}",
+        Msg::ImportSummary => "{}（{}、{}）をインポートしますか？",
+        Msg::OverlapWindow => "重複時間帯: {}",
+        Msg::NoOverlapHours => "重複する時間帯はありません",
+        Msg::InTimezone => "{}（{} 時間）",
+        Msg::MeetingsInOverlap => "重複時間帯内: {}",
+        Msg::MeetingsOutsideOverlap => "重複時間帯外: {}",
+        Msg::AllInWorkingHours => "\u{2713} すべてのタイムゾーンが勤務時間内です \u{2014} 最適！",
+        Msg::OutsideWorkingHours => "\u{26A0} {} は勤務時間外です",
+        Msg::Essential => "必須",
+        Msg::EssentialQuestion => "タイムゾーンをまたぐ共同作業に必須ですか？",
+        Msg::EditMeeting => "会議を編集",
+        Msg::MeetingTitle => "会議のタイトル",
+        Msg::Organizer => "主催者",
+        Msg::AddMeeting => "+ この時間に会議を追加",
+        Msg::Now => "現在",
+        Msg::NowAt => "現在: {}",
+        Msg::DialLabel => "会議時計。矢印キーで時間とリングを移動し、Enter で時間を選択します。",
+        Msg::WorkingHours => "勤務時間内",
+        Msg::OutsideHours => "勤務時間外",
+        Msg::MeetingsAtHour => "この時間: {}",
+        Msg::EssentialTag => "（必須）",
+        Msg::Removed => "削除しました: {}",
+        Msg::DeletedMeeting => "「{}」を削除しました",
+        Msg::DeletedMeetings => "{}を削除しました",
+        Msg::StorageUnavailable => "ブラウザのストレージが無効です（プライベートブラウズなど）。このタブを閉じると変更は失われます。",
+        Msg::StorageFull => "ブラウザのストレージがいっぱいです。最近の変更は保存されませんでした。",
+        Msg::StorageFailed => "設定を保存できませんでした: {}",
+        Msg::CustomizeTheme => "テーマをカスタマイズ",
+        Msg::ThemeNameLabel => "テーマ名",
+        Msg::StartFrom => "元にするテーマ",
+        Msg::EditingLight => "ライトモードの色を編集中です。もう一方のパレットはダークモードに切り替えて編集します。",
+        Msg::EditingDark => "ダークモードの色を編集中です。もう一方のパレットはライトモードに切り替えて編集します。",
+        Msg::LowContrast => "{}（背景 {}）: {}:1、必要 {}:1",
+        Msg::ContrastOk => "確認したすべてのテキストが WCAG AA のコントラスト基準を満たしています。",
+        Msg::Delete => "削除",
+        Msg::NewThemeName => "マイテーマ",
+        Msg::ConfirmDeleteTheme => "テーマ「{}」を削除しますか？",
+        Msg::ModeLight => "ライト",
+        Msg::ModeDark => "ダーク",
+        Msg::ModeSystem => "システム",
+        Msg::ModeSunset => "日没",
+        Msg::ModeSystemHint => "OSのライト/ダーク設定に従う",
+        Msg::SunsetToday => "日没（{}）から日の出（{}）までダーク（{}時間）",
+        Msg::SunNeverSets => "今日の{}では日が沈まないため、ライトのままです",
+        Msg::SunNeverRises => "今日の{}では日が昇らないため、ダークのままです",
+        Msg::HatchWorkingHours => "勤務時間を斜線で表示",
+        Msg::LayoutDial => "ダイヤル",
+        Msg::LayoutTimeline => "タイムライン",
+        Msg::TimelineLabel => "会議タイムライン。矢印キーで時間とタイムゾーンを移動し、Enter で時間を選択します。",
+        Msg::LayoutWeek => "週",
+        Msg::WeekLabel => "週間の重なり",
+        Msg::PreviousWeek => "前の週",
+        Msg::ThisWeek => "今週",
+        Msg::NextWeek => "次の週",
+        Msg::WeekCell => "{}/{} のゾーンが勤務中",
+        Msg::ClocksChangeThisWeek => "今週の時刻変更: {}",
+        Msg::UpcomingClockChanges => "今後の時刻変更",
+        Msg::ClockChangeIn => "{}: {} の時刻変更（{}後）",
+        Msg::ClockChangeToday => "{}: 今日 {} の時刻変更",
+        Msg::MeetingShift => "{}（{} UTC）は現地時間 {} になります",
+        Msg::JoinsOverlap => "全員の勤務時間内になります",
+        Msg::LeavesOverlap => "全員の勤務時間外になります",
+        Msg::YearOverlapTitle => "年間の重なり",
+        Msg::YearOverlapChart => "1日あたりの完全に重なる時間",
+        Msg::PreviousYear => "前の年",
+        Msg::NextYear => "次の年",
+        Msg::YearOverlapAverage => "平日平均の完全な重なり: {}",
+        Msg::WorstPeriods => "最も少ない重なり（平日あたり {}）: {}",
+        Msg::OverlapSteady => "重なりは一年中同じです",
+        Msg::ReorderRing => "ドラッグまたは矢印キーで並べ替え",
+        Msg::RingColorsPreview => "6番目以降のリングは隣り合う2つのリング色を混ぜて使います:",
+        Msg::SkippedHour => "時計が進むため存在しません",
+        Msg::RepeatedHour => "時計が戻るため2回あります",
+        Msg::WorkingHoursIn => "{}は勤務時間内",
+        Msg::OutsideHoursIn => "{}は勤務時間外",
+        Msg::ShortHours => "{}時間",
+        Msg::ShortMinutes => "{}分",
+        Msg::ImportInvalidJson => "有効なJSONではありません（{}）",
+        Msg::ImportNotAnObject => "最上位にはJSONオブジェクトが必要です",
+        Msg::ImportMissingFormat => "ありません。\"{}\"が必要です",
+        Msg::ImportWrongFormat => "\"{}\"が必要ですが、{}でした",
+        Msg::ImportUnsupportedVersion => "バージョン{}には対応していません。このアプリはバージョン{}を読み込みます",
+        Msg::ImportMissing => "ありません",
+        Msg::ImportEmpty => "空にはできません",
+        Msg::ImportNoWorkspaces => "ワークスペースが1つ以上必要です",
+        Msg::ImportExpectedString => "文字列が必要ですが、{}でした",
+        Msg::ImportExpectedArray => "配列が必要ですが、{}でした",
+        Msg::ImportExpectedObject => "オブジェクトが必要ですが、{}でした",
+        Msg::ImportExpectedBool => "trueまたはfalseが必要ですが、{}でした",
+        Msg::ImportExpectedZone => "{\"id\": \"europe_london\"}のようなオブジェクトが必要ですが、{}でした",
+        Msg::ImportUnknownMode => "\"Light\"、\"Dark\"、\"System\"、\"Sunset\"のいずれかが必要ですが、\"{}\"でした",
+        Msg::ImportUnknownTheme => "不明なテーマ\"{}\"（{}のいずれかが必要です）",
+        Msg::ImportUnknownReference => "\"first\"、\"utc\"、\"local\"またはゾーンIDが必要ですが、\"{}\"でした",
+        Msg::ImportUnknownZone => "不明なゾーンID\"{}\"",
+        Msg::ImportDuplicateZone => "ゾーン\"{}\"が重複しています",
+        Msg::ImportZoneCount => "ゾーンは1～{}個必要ですが、{}個ありました",
+        Msg::ImportNotInWorkspace => "\"{}\"はこのワークスペースのゾーンではありません",
+        Msg::ImportHourOutOfRange => "{}～{}の時刻が必要ですが、{}でした",
+        Msg::ImportEndNotAfterStart => "work_startより後にしてください（{} >= {}）",
+        Msg::ImportNotAColorSlot => "0～{}の色番号が必要ですが、{}でした",
+        Msg::ImportNotAUtcHour => "0～23の整数の時刻が必要ですが、{}でした",
+        Msg::ImportNotAnId => "正の整数が必要ですが、{}でした",
+        Msg::ImportUnknownColorKey => "不明な色キー",
+        Msg::ImportNotAColor => "\"#1a2b3c\"のような色が必要ですが、{}でした",
+        Msg::ImportDuplicateMeetingId => "会議ID {} が重複しています",
+    }
+}
+
+fn ja_count(noun: Count) -> (&'static str, &'static str) {
+    match noun {
+        Count::Hours => ("{} 時間", "{} 時間"),
+        Count::Meetings => ("{} 件の会議", "{} 件の会議"),
+        Count::Workspaces => ("{} 個のワークスペース", "{} 個のワークスペース"),
+        Count::Zones => ("{} 個のタイムゾーン", "{} 個のタイムゾーン"),
+        Count::Problems => ("{} 件の問題", "{} 件の問題"),
+        Count::Days => ("{} 日", "{} 日"),
+    }
+}
+
+/// Zone display names: (id, pt-BR, de, ja); empty means the English name is used
+static ZONE_NAMES: &[(&str, &str, &str, &str)] = &[
+    ("pacific_baker", "Ilha Baker", "Baker-Insel", "ベーカー島"),
+    ("pacific_samoa", "", "", "パゴパゴ (SST)"),
+    ("pacific_honolulu", "", "", "ホノルル (HST)"),
+    ("pacific_marquesas", "Ilhas Marquesas", "Marquesas-Inseln", "マルキーズ諸島"),
+    ("america_anchorage", "", "", "アンカレッジ (AKST)"),
+    ("america_los_angeles", "", "", "ロサンゼルス (PST)"),
+    ("america_denver", "", "", "デンバー (MST)"),
+    ("america_chicago", "", "", "ダラス (CST)"),
+    ("america_new_york", "Nova York (EST)", "", "ニューヨーク (EST)"),
+    ("america_caracas", "", "", "カラカス (VET)"),
+    ("america_st_johns", "", "", "セントジョンズ (NST)"),
+    ("america_sao_paulo", "", "", "サンパウロ (BRT)"),
+    ("atlantic_south_georgia", "Geórgia do Sul", "Südgeorgien", "サウスジョージア"),
+    ("atlantic_azores", "Açores (AZOT)", "Azoren (AZOT)", "アゾレス諸島 (AZOT)"),
+    ("europe_london", "Londres (GMT)", "", "ロンドン (GMT)"),
+    ("europe_paris", "", "", "パリ (CET)"),
+    ("europe_berlin", "", "", "ベルリン (CET)"),
+    ("africa_cairo", "", "Kairo (EET)", "カイロ (EET)"),
+    ("europe_moscow", "Moscou (MSK)", "Moskau (MSK)", "モスクワ (MSK)"),
+    ("asia_tehran", "Teerã (IRST)", "Teheran (IRST)", "テヘラン (IRST)"),
+    ("asia_dubai", "", "", "ドバイ (GST)"),
+    ("asia_kabul", "Cabul (AFT)", "", "カブール (AFT)"),
+    ("asia_karachi", "", "", "カラチ (PKT)"),
+    ("asia_kolkata", "Índia (IST)", "Indien (IST)", "インド (IST)"),
+    ("asia_kathmandu", "Katmandu (NPT)", "", "カトマンズ (NPT)"),
+    ("asia_dhaka", "Daca (BST)", "", "ダッカ (BST)"),
+    ("asia_yangon", "", "Rangun (MMT)", "ヤンゴン (MMT)"),
+    ("asia_bangkok", "Bangcoc (ICT)", "", "バンコク (ICT)"),
+    ("asia_shanghai", "Xangai (CST)", "Schanghai (CST)", "上海 (CST)"),
+    ("asia_hong_kong", "", "Hongkong (HKT)", "香港 (HKT)"),
+    ("asia_singapore", "Singapura (SGT)", "Singapur (SGT)", "シンガポール (SGT)"),
+    ("australia_eucla", "", "", "ユークラ (ACWST)"),
+    ("asia_tokyo", "Tóquio (JST)", "", "東京 (JST)"),
+    ("australia_darwin", "", "", "ダーウィン (ACST)"),
+    ("australia_sydney", "", "", "シドニー (AEST)"),
+    ("australia_lhi", "Ilha de Lord Howe", "Lord-Howe-Insel", "ロード・ハウ島"),
+    ("pacific_noumea", "", "", "ヌメア (NCT)"),
+    ("pacific_auckland", "", "", "オークランド (NZST)"),
+    ("pacific_chatham", "Ilhas Chatham", "Chatham-Inseln", "チャタム諸島"),
+    ("pacific_tongatapu", "", "", "トンガ (TOT)"),
+    ("pacific_kiritimati", "", "", "キリティマティ (LINT)"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::timezone_db::zone_by_id;
+
+    #[test]
+    fn test_plurals() {
+        assert_eq!(Language::En.count(Count::Meetings, 1), "1 meeting");
+        assert_eq!(Language::En.count(Count::Meetings, 0), "0 meetings");
+        assert_eq!(Language::PtBr.count(Count::Meetings, 0), "0 reunião");
+        assert_eq!(Language::PtBr.count(Count::Meetings, 2), "2 reuniões");
+        assert_eq!(Language::De.count(Count::Hours, 1), "1 Stunde");
+        assert_eq!(Language::Ja.count(Count::Meetings, 1), "1 件の会議");
+    }
+
+    #[test]
+    fn test_fill_and_lookup() {
+        let overlap = Language::En.count(Count::Hours, 3);
+        assert_eq!(Language::En.fill(Msg::OverlapWindow, &[&overlap]), "Overlap Window: 3 hours");
+        assert_eq!(Language::De.fill(Msg::ImportSummary, &["a", "b", "c"]), "a mit b und c importieren?");
+        assert_eq!(Language::from_tag("pt-PT"), Some(Language::PtBr));
+        assert_eq!(Language::from_tag("ja_JP"), Some(Language::Ja));
+        assert_eq!(Language::from_tag("fr"), None);
+        assert_eq!(Language::from_code("pt-BR"), Some(Language::PtBr));
+
+        let tokyo = zone_by_id("asia_tokyo").unwrap();
+        assert_eq!(Language::Ja.zone_name(tokyo), "東京 (JST)");
+        assert_eq!(Language::De.zone_name(tokyo), "Tokyo (JST)");
+    }
+
+    #[test]
+    fn test_units() {
+        assert_eq!(Language::En.shift(1.0), "+1h");
+        assert_eq!(Language::En.shift(-0.5), "-30m");
+        assert_eq!(Language::De.shift(-1.0), "-1 Std.");
+        assert_eq!(Language::De.short_hours(4.5, 1), "4,5 Std.");
+        assert_eq!(Language::Ja.short_hours(8.0, 0), "8時間");
+
+        let error = ImportError { path: "zones[0].id".into(), problem: ImportProblem::UnknownZone("x".into()) };
+        assert_eq!(Language::De.import_error(&error), "zones[0].id: unbekannte Zonen-ID \"x\"");
+    }
+
+    #[test]
+    fn test_error_display_is_the_english_catalog() {
+        let error = ImportError { path: String::new(), problem: ImportProblem::NotAnObject };
+        assert_eq!(error.to_string(), Language::En.t(Msg::ImportNotAnObject));
+        assert_eq!(StoreError::QuotaExceeded.to_string(), Language::En.t(Msg::StorageFull));
+        assert_eq!(StoreError::Failed("x".into()).to_string(), Language::En.fill(Msg::StorageFailed, &["x"]));
+    }
+
+    #[test]
+    fn test_zone_name_table_uses_known_ids() {
+        for (id, ..) in ZONE_NAMES {
+            assert!(zone_by_id(id).is_some(), "unknown zone id {}", id);
+        }
+    }
+}
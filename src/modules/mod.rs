@@ -3,9 +3,31 @@ pub mod timezone;
 pub mod timezone_db;
 pub mod geometry;
 pub mod themes;
+pub mod embed;
+pub mod history;
+pub mod workspace;
+pub mod config_io;
+pub mod store;
+pub mod time_format;
+pub mod web_store;
+pub mod i18n;
+pub mod custom_theme;
+pub mod sun;
+pub mod calendar;
 
 pub use types::*;
 pub use timezone::*;
 pub use timezone_db::*;
 pub use geometry::*;
 pub use themes::*;
+pub use embed::*;
+pub use history::*;
+pub use workspace::*;
+pub use config_io::*;
+pub use store::*;
+pub use time_format::*;
+pub use web_store::*;
+pub use i18n::*;
+pub use custom_theme::*;
+pub use sun::*;
+pub use calendar::*;
@@ -0,0 +1,241 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use crate::modules::custom_theme::{custom_themes_from_json, custom_themes_to_json, CustomTheme};
+use crate::modules::i18n::Language;
+use crate::modules::themes::{ModeSetting, ThemeName};
+use crate::modules::time_format::HourCycle;
+use crate::modules::timezone_db::zone_by_id;
+use crate::modules::types::{ActiveTimezones, ClockLayout, TimezoneEntry};
+use crate::modules::workspace::{workspaces_from_json, workspaces_to_json, Workspace};
+
+// Pre-workspace keys, only read to migrate an existing setup into the first workspace
+pub const STORAGE_KEY_ZONES: &str = "tz-clock-zones";
+pub const STORAGE_KEY_THEME: &str = "tz-clock-theme";
+pub const STORAGE_KEY_MODE: &str = "tz-clock-mode";
+pub const STORAGE_KEY_HOUR_CYCLE: &str = "tz-clock-hour-cycle";
+pub const STORAGE_KEY_HATCH: &str = "tz-clock-hatch";
+pub const STORAGE_KEY_LAYOUT: &str = "tz-clock-layout";
+pub const STORAGE_KEY_LANGUAGE: &str = "tz-clock-language";
+pub const STORAGE_KEY_THEMES: &str = "tz-clock-themes";
+pub const STORAGE_KEY_WORKSPACES: &str = "tz-clock-workspaces";
+pub const STORAGE_KEY_ACTIVE_WORKSPACE: &str = "tz-clock-active-workspace";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StoreError {
+    /// Storage is disabled or blocked, e.g. private browsing or site data turned off
+    Unavailable,
+    QuotaExceeded,
+    Failed(String),
+}
+
+impl std::fmt::Display for StoreError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&Language::En.store_error(self))
+    }
+}
+
+/// String key/value persistence behind the app's load/save functions.
+pub trait StateStore {
+    fn get(&self, key: &str) -> Result<Option<String>, StoreError>;
+    fn set(&self, key: &str, value: &str) -> Result<(), StoreError>;
+
+    /// Register a handler for failures found after `set` returned, for
+    /// backends that write asynchronously.
+    fn on_async_error(&self, _handler: Rc<dyn Fn(StoreError)>) {}
+}
+
+/// Keeps everything in memory; used when browser storage is unavailable, and by tests.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    items: RefCell<HashMap<String, String>>,
+    quota: Option<usize>,
+}
+
+impl MemoryStore {
+    /// A store that rejects writes once keys plus values exceed `bytes`
+    #[cfg(test)]
+    pub fn with_quota(bytes: usize) -> Self {
+        Self { quota: Some(bytes), ..Self::default() }
+    }
+}
+
+impl StateStore for MemoryStore {
+    fn get(&self, key: &str) -> Result<Option<String>, StoreError> {
+        Ok(self.items.borrow().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StoreError> {
+        let mut items = self.items.borrow_mut();
+        if let Some(quota) = self.quota {
+            let used: usize = items.iter()
+                .filter(|(k, _)| k.as_str() != key)
+                .map(|(k, v)| k.len() + v.len())
+                .sum();
+            if used + key.len() + value.len() > quota {
+                return Err(StoreError::QuotaExceeded);
+            }
+        }
+        items.insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+}
+
+pub fn load_mode(store: &dyn StateStore) -> Result<ModeSetting, StoreError> {
+    Ok(store.get(STORAGE_KEY_MODE)?
+        .and_then(|v| ModeSetting::from_label(&v))
+        .unwrap_or_default())
+}
+
+pub fn save_mode(store: &dyn StateStore, mode: ModeSetting) -> Result<(), StoreError> {
+    store.set(STORAGE_KEY_MODE, mode.label())
+}
+
+/// `None` until the user picks one, so the locale's preference applies
+pub fn load_hour_cycle(store: &dyn StateStore) -> Result<Option<HourCycle>, StoreError> {
+    Ok(store.get(STORAGE_KEY_HOUR_CYCLE)?.and_then(|v| HourCycle::from_label(&v)))
+}
+
+pub fn save_hour_cycle(store: &dyn StateStore, cycle: HourCycle) -> Result<(), StoreError> {
+    store.set(STORAGE_KEY_HOUR_CYCLE, cycle.label())
+}
+
+/// Whether working hours are striped as well as colored; off by default
+pub fn load_hatch(store: &dyn StateStore) -> Result<bool, StoreError> {
+    Ok(store.get(STORAGE_KEY_HATCH)?.as_deref() == Some("on"))
+}
+
+pub fn save_hatch(store: &dyn StateStore, hatch: bool) -> Result<(), StoreError> {
+    store.set(STORAGE_KEY_HATCH, if hatch { "on" } else { "off" })
+}
+
+pub fn load_layout(store: &dyn StateStore) -> Result<ClockLayout, StoreError> {
+    Ok(store.get(STORAGE_KEY_LAYOUT)?.and_then(|v| ClockLayout::from_label(&v)).unwrap_or_default())
+}
+
+pub fn save_layout(store: &dyn StateStore, layout: ClockLayout) -> Result<(), StoreError> {
+    store.set(STORAGE_KEY_LAYOUT, layout.label())
+}
+
+/// `None` until the user picks one, so the browser language applies
+pub fn load_language(store: &dyn StateStore) -> Result<Option<Language>, StoreError> {
+    Ok(store.get(STORAGE_KEY_LANGUAGE)?.and_then(|v| Language::from_code(&v)))
+}
+
+pub fn save_language(store: &dyn StateStore, language: Language) -> Result<(), StoreError> {
+    store.set(STORAGE_KEY_LANGUAGE, language.code())
+}
+
+pub fn load_custom_themes(store: &dyn StateStore) -> Result<Vec<CustomTheme>, StoreError> {
+    Ok(store.get(STORAGE_KEY_THEMES)?.and_then(|raw| custom_themes_from_json(&raw)).unwrap_or_default())
+}
+
+pub fn save_custom_themes(store: &dyn StateStore, themes: &[CustomTheme]) -> Result<(), StoreError> {
+    store.set(STORAGE_KEY_THEMES, &custom_themes_to_json(themes))
+}
+
+/// Load all workspaces and the active workspace id. On first run the
+/// legacy single configuration becomes a "Default" workspace; a first-time
+/// visitor gets `local_zone` (the browser's zone) on the first ring.
+pub fn load_workspaces(store: &dyn StateStore, local_zone: Option<&TimezoneEntry>) -> Result<(Vec<Workspace>, u32), StoreError> {
+    if let Some(workspaces) = store.get(STORAGE_KEY_WORKSPACES)?.and_then(|raw| workspaces_from_json(&raw)) {
+        let active = store.get(STORAGE_KEY_ACTIVE_WORKSPACE)?
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|id| workspaces.iter().any(|w| w.id == *id))
+            .unwrap_or(workspaces[0].id);
+        return Ok((workspaces, active));
+    }
+
+    let mut workspace = Workspace::new(1, "Default");
+    let legacy: Vec<TimezoneEntry> = store.get(STORAGE_KEY_ZONES)?
+        .map(|raw| raw.split(',').filter_map(zone_by_id).cloned().collect())
+        .unwrap_or_default();
+    if !legacy.is_empty() {
+        workspace.zones = ActiveTimezones::new(legacy);
+    } else if let Some(local) = local_zone {
+        workspace.zones = ActiveTimezones::with_local_first(local);
+    }
+    if let Some(theme) = store.get(STORAGE_KEY_THEME)?.and_then(|v| ThemeName::from_label(&v)) {
+        workspace.theme = theme;
+    }
+    Ok((vec![workspace], 1))
+}
+
+pub fn save_workspaces(store: &dyn StateStore, workspaces: &[Workspace], active: u32) -> Result<(), StoreError> {
+    store.set(STORAGE_KEY_WORKSPACES, &workspaces_to_json(workspaces))?;
+    store.set(STORAGE_KEY_ACTIVE_WORKSPACE, &active.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_workspaces_round_trip() {
+        let store = MemoryStore::default();
+        let mut team = Workspace::new(2, "Team");
+        team.theme = ThemeName::Custom(4);
+        let workspaces = vec![Workspace::new(1, "Default"), team];
+        save_workspaces(&store, &workspaces, 2).unwrap();
+        assert_eq!(load_workspaces(&store, None), Ok((workspaces.clone(), 2)));
+
+        // A stale active id falls back to the first workspace
+        store.set(STORAGE_KEY_ACTIVE_WORKSPACE, "9").unwrap();
+        assert_eq!(load_workspaces(&store, None), Ok((workspaces, 1)));
+    }
+
+    #[test]
+    fn test_first_visit_uses_local_zone() {
+        let store = MemoryStore::default();
+        let tokyo = zone_by_id("asia_tokyo");
+        let (workspaces, _) = load_workspaces(&store, tokyo).unwrap();
+        let ids: Vec<&str> = workspaces[0].zones.zones().iter().map(|z| z.id).collect();
+        assert_eq!(ids, vec!["asia_tokyo", "america_chicago", "america_new_york", "europe_london"]);
+
+        // Already a default ring: moved to the front rather than duplicated
+        let (workspaces, _) = load_workspaces(&store, zone_by_id("europe_london")).unwrap();
+        let ids: Vec<&str> = workspaces[0].zones.zones().iter().map(|z| z.id).collect();
+        assert_eq!(ids, vec!["europe_london", "america_chicago", "america_new_york"]);
+    }
+
+    #[test]
+    fn test_legacy_migration() {
+        let store = MemoryStore::default();
+        store.set(STORAGE_KEY_ZONES, "asia_tokyo,mars_olympus").unwrap();
+        store.set(STORAGE_KEY_THEME, "Bold").unwrap();
+        // An existing setup keeps its rings even when the browser zone is known
+        let (workspaces, active) = load_workspaces(&store, zone_by_id("europe_paris")).unwrap();
+        assert_eq!(active, 1);
+        assert_eq!(workspaces[0].name, "Default");
+        assert_eq!(workspaces[0].theme, ThemeName::Bold);
+        let ids: Vec<&str> = workspaces[0].zones.zones().iter().map(|z| z.id).collect();
+        assert_eq!(ids, vec!["asia_tokyo"]);
+    }
+
+    #[test]
+    fn test_mode_and_quota() {
+        let store = MemoryStore::default();
+        assert_eq!(load_mode(&store), Ok(ModeSetting::System));
+        save_mode(&store, ModeSetting::Sunset).unwrap();
+        assert_eq!(load_mode(&store), Ok(ModeSetting::Sunset));
+        assert_eq!(load_hour_cycle(&store), Ok(None));
+        save_hour_cycle(&store, HourCycle::H12).unwrap();
+        assert_eq!(load_hour_cycle(&store), Ok(Some(HourCycle::H12)));
+        assert_eq!(load_layout(&store), Ok(ClockLayout::Dial));
+        save_layout(&store, ClockLayout::Timeline).unwrap();
+        assert_eq!(load_layout(&store), Ok(ClockLayout::Timeline));
+        assert_eq!(load_hatch(&store), Ok(false));
+        save_hatch(&store, true).unwrap();
+        assert_eq!(load_hatch(&store), Ok(true));
+        assert_eq!(load_language(&store), Ok(None));
+        save_language(&store, Language::PtBr).unwrap();
+        assert_eq!(load_language(&store), Ok(Some(Language::PtBr)));
+
+        let full = MemoryStore::with_quota(64);
+        assert_eq!(save_mode(&full, ModeSetting::Light), Ok(()));
+        let many: Vec<Workspace> = (1..=5).map(|id| Workspace::new(id, "Workspace")).collect();
+        assert_eq!(save_workspaces(&full, &many, 1), Err(StoreError::QuotaExceeded));
+        // The earlier value survives a rejected write
+        assert_eq!(load_mode(&full), Ok(ModeSetting::Light));
+    }
+}
@@ -0,0 +1,141 @@
+//! Sunrise and sunset for the "Sunset" appearance schedule, using NOAA's
+//! low-precision solar position formulas (accurate to a few minutes).
+
+use std::f64::consts::PI;
+use crate::modules::types::TimezoneEntry;
+
+/// Daylight on one day at one place; times are UTC hours in [0, 24)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Daylight {
+    Times { sunrise: f64, sunset: f64 },
+    /// The sun does not set (polar summer)
+    PolarDay,
+    /// The sun does not rise (polar winter)
+    PolarNight,
+}
+
+impl Daylight {
+    pub fn is_dark(&self, utc_hour: f64) -> bool {
+        match *self {
+            Daylight::PolarDay => false,
+            Daylight::PolarNight => true,
+            Daylight::Times { sunrise, sunset } => {
+                let t = utc_hour.rem_euclid(24.0);
+                if sunrise < sunset {
+                    !(sunrise..sunset).contains(&t)
+                } else {
+                    // Daytime wraps past midnight UTC
+                    (sunset..sunrise).contains(&t)
+                }
+            }
+        }
+    }
+}
+
+/// Daylight on `day_of_year` (1-366) at latitude `lat`, longitude `lon` (degrees, east positive)
+pub fn daylight(day_of_year: u32, lat: f64, lon: f64) -> Daylight {
+    let g = 2.0 * PI / 365.0 * (day_of_year as f64 - 1.0);
+    let eq_time = 229.18 * (0.000075 + 0.001868 * g.cos() - 0.032077 * g.sin()
+        - 0.014615 * (2.0 * g).cos() - 0.040849 * (2.0 * g).sin());
+    let decl = 0.006918 - 0.399912 * g.cos() + 0.070257 * g.sin() - 0.006758 * (2.0 * g).cos()
+        + 0.000907 * (2.0 * g).sin() - 0.002697 * (3.0 * g).cos() + 0.00148 * (3.0 * g).sin();
+    let lat = lat.to_radians();
+    // 90.833° accounts for refraction and the sun's radius
+    let cos_ha = 90.833_f64.to_radians().cos() / (lat.cos() * decl.cos()) - lat.tan() * decl.tan();
+    if cos_ha > 1.0 {
+        return Daylight::PolarNight;
+    }
+    if cos_ha < -1.0 {
+        return Daylight::PolarDay;
+    }
+    let ha = cos_ha.acos().to_degrees();
+    let minutes = |h: f64| (720.0 - 4.0 * (lon + h) - eq_time).rem_euclid(1440.0);
+    Daylight::Times { sunrise: minutes(ha) / 60.0, sunset: minutes(-ha) / 60.0 }
+}
+
+/// Approximate (latitude, longitude) of the city a zone is named after.
+/// Zones without one use the equator at the zone's central meridian.
+pub fn zone_coordinates(tz: &TimezoneEntry) -> (f64, f64) {
+    ZONE_COORDINATES.iter()
+        .find(|(id, ..)| *id == tz.id)
+        .map(|&(_, lat, lon)| (lat, lon))
+        .unwrap_or((0.0, tz.utc_offset * 15.0))
+}
+
+static ZONE_COORDINATES: &[(&str, f64, f64)] = &[
+    ("pacific_baker", 0.19, -176.48),
+    ("pacific_samoa", -14.28, -170.70),
+    ("pacific_honolulu", 21.31, -157.86),
+    ("pacific_marquesas", -9.00, -139.50),
+    ("america_anchorage", 61.22, -149.90),
+    ("america_los_angeles", 34.05, -118.24),
+    ("america_denver", 39.74, -104.99),
+    ("america_chicago", 32.78, -96.80),
+    ("america_new_york", 40.71, -74.01),
+    ("america_caracas", 10.48, -66.90),
+    ("america_st_johns", 47.56, -52.71),
+    ("america_sao_paulo", -23.55, -46.63),
+    ("atlantic_south_georgia", -54.28, -36.51),
+    ("atlantic_azores", 37.74, -25.67),
+    ("europe_london", 51.51, -0.13),
+    ("europe_paris", 48.86, 2.35),
+    ("europe_berlin", 52.52, 13.40),
+    ("africa_cairo", 30.04, 31.24),
+    ("europe_moscow", 55.76, 37.62),
+    ("asia_tehran", 35.69, 51.39),
+    ("asia_dubai", 25.20, 55.27),
+    ("asia_kabul", 34.53, 69.17),
+    ("asia_karachi", 24.86, 67.01),
+    ("asia_kolkata", 28.61, 77.21),
+    ("asia_kathmandu", 27.72, 85.32),
+    ("asia_dhaka", 23.81, 90.41),
+    ("asia_yangon", 16.87, 96.20),
+    ("asia_bangkok", 13.76, 100.50),
+    ("asia_shanghai", 31.23, 121.47),
+    ("asia_hong_kong", 22.32, 114.17),
+    ("asia_singapore", 1.35, 103.82),
+    ("australia_eucla", -31.68, 128.88),
+    ("asia_tokyo", 35.68, 139.69),
+    ("australia_darwin", -12.46, 130.84),
+    ("australia_sydney", -33.87, 151.21),
+    ("australia_lhi", -31.55, 159.08),
+    ("pacific_noumea", -22.28, 166.46),
+    ("pacific_auckland", -36.85, 174.76),
+    ("pacific_chatham", -43.95, -176.56),
+    ("pacific_tongatapu", -21.14, -175.20),
+    ("pacific_kiritimati", 1.87, -157.43),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::timezone_db::{zone_by_id, TIMEZONE_DATABASE};
+
+    #[test]
+    fn test_london_solstice() {
+        // 21 June: sunrise about 03:43 UTC, sunset about 20:21 UTC
+        let (lat, lon) = zone_coordinates(zone_by_id("europe_london").unwrap());
+        let Daylight::Times { sunrise, sunset } = daylight(172, lat, lon) else { panic!("expected sunrise and sunset") };
+        assert!((sunrise - 3.72).abs() < 0.1, "sunrise {}", sunrise);
+        assert!((sunset - 20.35).abs() < 0.1, "sunset {}", sunset);
+        assert!(daylight(172, lat, lon).is_dark(22.0));
+        assert!(!daylight(172, lat, lon).is_dark(12.0));
+    }
+
+    #[test]
+    fn test_wrapping_and_polar() {
+        // Tokyo's day straddles midnight UTC
+        let tokyo = daylight(80, 35.68, 139.69);
+        assert!(!tokyo.is_dark(0.0));
+        assert!(tokyo.is_dark(12.0));
+        assert_eq!(daylight(172, 78.2, 15.6), Daylight::PolarDay);
+        assert_eq!(daylight(355, 78.2, 15.6), Daylight::PolarNight);
+    }
+
+    #[test]
+    fn test_every_zone_has_coordinates() {
+        for tz in TIMEZONE_DATABASE {
+            assert!(ZONE_COORDINATES.iter().any(|(id, ..)| *id == tz.id), "{}", tz.id);
+        }
+    }
+}
@@ -4,6 +4,12 @@ pub enum ThemeName {
     Bold,
     Professional,
     Playful,
+    /// Maximum contrast: black and white with a yellow/blue working-hours fill
+    HighContrast,
+    /// Blue/orange instead of red/green, for deuteranopia and protanopia
+    ColorblindSafe,
+    /// A user-defined theme, by its id in the custom theme list
+    Custom(u32),
 }
 
 impl ThemeName {
@@ -13,14 +19,39 @@ impl ThemeName {
             ThemeName::Bold => "Bold",
             ThemeName::Professional => "Professional",
             ThemeName::Playful => "Playful",
+            ThemeName::HighContrast => "High-Contrast",
+            ThemeName::ColorblindSafe => "Colorblind-Safe",
+            ThemeName::Custom(_) => "Custom",
         }
     }
 
-    pub const ALL: [ThemeName; 4] = [
+    /// Case-insensitive inverse of [`ThemeName::label`], for the built-in themes.
+    pub fn from_label(label: &str) -> Option<ThemeName> {
+        ThemeName::ALL.into_iter().find(|tn| tn.label().eq_ignore_ascii_case(label))
+    }
+
+    /// Persisted form: the label for built-in themes, "custom-<id>" otherwise
+    pub fn key(&self) -> String {
+        match self {
+            ThemeName::Custom(id) => format!("custom-{}", id),
+            builtin => builtin.label().to_string(),
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<ThemeName> {
+        match key.strip_prefix("custom-") {
+            Some(id) => id.parse().ok().map(ThemeName::Custom),
+            None => ThemeName::from_label(key),
+        }
+    }
+
+    pub const ALL: [ThemeName; 6] = [
         ThemeName::Minimalist,
         ThemeName::Bold,
         ThemeName::Professional,
         ThemeName::Playful,
+        ThemeName::HighContrast,
+        ThemeName::ColorblindSafe,
     ];
 }
 
@@ -30,10 +61,68 @@ pub enum Mode {
     Dark,
 }
 
+impl Mode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Mode::Light => "Light",
+            Mode::Dark => "Dark",
+        }
+    }
+
+    /// Case-insensitive inverse of [`Mode::label`].
+    pub fn from_label(label: &str) -> Option<Mode> {
+        [Mode::Light, Mode::Dark].into_iter().find(|m| m.label().eq_ignore_ascii_case(label))
+    }
+}
+
+/// The appearance the user picked; the app resolves it to a [`Mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ModeSetting {
+    Light,
+    Dark,
+    /// Follow the operating system's `prefers-color-scheme`
+    #[default]
+    System,
+    /// Dark between sunset and sunrise in the reference zone
+    Sunset,
+}
+
+impl ModeSetting {
+    pub const ALL: [ModeSetting; 4] = [
+        ModeSetting::Light,
+        ModeSetting::Dark,
+        ModeSetting::System,
+        ModeSetting::Sunset,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ModeSetting::Light => "Light",
+            ModeSetting::Dark => "Dark",
+            ModeSetting::System => "System",
+            ModeSetting::Sunset => "Sunset",
+        }
+    }
+
+    /// Case-insensitive inverse of [`ModeSetting::label`].
+    pub fn from_label(label: &str) -> Option<ModeSetting> {
+        ModeSetting::ALL.into_iter().find(|m| m.label().eq_ignore_ascii_case(label))
+    }
+}
+
+impl From<Mode> for ModeSetting {
+    fn from(mode: Mode) -> Self {
+        match mode {
+            Mode::Light => ModeSetting::Light,
+            Mode::Dark => ModeSetting::Dark,
+        }
+    }
+}
+
 // Some fields are only accessed inside view! proc macros,
 // which the dead_code analyzer cannot see through.
 #[allow(dead_code)]
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ThemeColors {
     pub background: &'static str,
     pub card_bg: &'static str,
@@ -42,8 +131,9 @@ pub struct ThemeColors {
     pub text_secondary: &'static str,
     pub text_muted: &'static str,
     pub segment_stroke: &'static str,
-    pub ring_defaults: [&'static str; 5],
-    pub ring_text_colors: [&'static str; 5],
+    /// Ring fills, repeated outwards-in when there are more rings than colors
+    pub ring_defaults: [&'static str; RING_PALETTE],
+    pub ring_text_colors: [&'static str; RING_PALETTE],
     pub svg_bg: &'static str,
     pub svg_border: &'static str,
     pub center_circle_bg: &'static str,
@@ -64,8 +154,188 @@ pub struct ThemeColors {
     pub warning_text_dark: &'static str,
     pub meeting_essential: &'static str,
     pub meeting_non_essential: &'static str,
+    /// Fill of segments inside a zone's working hours
+    pub working_hours: &'static str,
+    /// Stripes drawn over working-hours segments when hatching is on
+    pub working_hours_hatch: &'static str,
+    /// The current-time line and the center circle's border
+    pub now_line: &'static str,
+}
+
+/// Colors per ring palette; further palette slots are mixed from them
+pub const RING_PALETTE: usize = 5;
+
+/// Share of a ring's label color in the fill of slots past the palette
+const RING_TINT: u32 = 20;
+
+/// Colors by their key in [`COLOR_KEYS`]: a built-in [`ThemeColors`], or a
+/// custom theme being edited, whose colors are owned rather than `'static`
+pub trait Palette {
+    fn color(&self, key: &str) -> Option<&str>;
+
+    /// Fill of rings with palette slot `slot`. The built-in themes give all
+    /// five rings one fill, so slots past the palette take a tint of their
+    /// label color to tell them apart.
+    fn ring_fill(&self, slot: usize) -> String {
+        let fill = ring_color(|i| self.color(&format!("ring_{}", i + 1)), slot);
+        if slot < RING_PALETTE {
+            return fill;
+        }
+        format!("color-mix(in oklch, {}, {} {}%)", fill, self.ring_text(slot), RING_TINT)
+    }
+
+    /// Label color of rings with palette slot `slot`
+    fn ring_text(&self, slot: usize) -> String {
+        ring_color(|i| self.color(&format!("ring_{}_text", i + 1)), slot)
+    }
+}
+
+/// Color of palette slot `slot` from a theme's five ring colors. The first
+/// five are used as they are; each later round of five mixes every color
+/// with the next one at a new ratio (1/2, 1/4, 3/4, 1/8, ...), so no two
+/// slots share a color as long as the five differ.
+fn ring_color<'a>(colors: impl Fn(usize) -> Option<&'a str>, slot: usize) -> String {
+    let (round, i) = (slot / RING_PALETTE, slot % RING_PALETTE);
+    let color = |i: usize| colors(i).unwrap_or_default().to_string();
+    if round == 0 {
+        return color(i);
+    }
+    // Van der Corput sequence: bits of the round mirrored behind the binary point
+    let share = (0..usize::BITS - round.leading_zeros())
+        .filter(|bit| round >> bit & 1 == 1)
+        .map(|bit| 0.5f64.powi(bit as i32 + 1))
+        .sum::<f64>();
+    format!("color-mix(in oklch, {}, {} {}%)", color(i), color((i + 1) % RING_PALETTE), share * 100.0)
 }
 
+/// Every editable color, by the key used in theme files
+pub const COLOR_KEYS: [&str; 40] = [
+    "background", "card_bg", "card_border", "text_primary", "text_secondary", "text_muted",
+    "segment_stroke", "svg_bg", "svg_border", "center_circle_bg", "ring_divider", "now_highlight",
+    "button_primary_bg", "button_primary_text", "button_secondary_bg", "input_border", "input_bg",
+    "success_bg", "success_border", "success_text", "success_text_dark",
+    "warning_bg", "warning_border", "warning_text", "warning_text_dark",
+    "meeting_essential", "meeting_non_essential", "working_hours", "working_hours_hatch", "now_line",
+    "ring_1", "ring_2", "ring_3", "ring_4", "ring_5",
+    "ring_1_text", "ring_2_text", "ring_3_text", "ring_4_text", "ring_5_text",
+];
+
+impl ThemeColors {
+    pub fn color(&self, key: &str) -> Option<&'static str> {
+        let mut copy = *self;
+        copy.slot(key).map(|c| *c)
+    }
+
+    /// Returns false for an unknown key
+    pub fn set_color(&mut self, key: &str, value: &'static str) -> bool {
+        self.slot(key).map(|c| *c = value).is_some()
+    }
+
+    fn slot(&mut self, key: &str) -> Option<&mut &'static str> {
+        // "ring_3" is ring_defaults[2], "ring_3_text" is ring_text_colors[2]
+        if let Some(rest) = key.strip_prefix("ring_") {
+            let (n, text) = match rest.strip_suffix("_text") {
+                Some(n) => (n, true),
+                None => (rest, false),
+            };
+            if let Some(i) = n.parse::<usize>().ok().filter(|n| (1..=RING_PALETTE).contains(n)) {
+                return Some(if text { &mut self.ring_text_colors[i - 1] } else { &mut self.ring_defaults[i - 1] });
+            }
+        }
+        Some(match key {
+            "background" => &mut self.background,
+            "card_bg" => &mut self.card_bg,
+            "card_border" => &mut self.card_border,
+            "text_primary" => &mut self.text_primary,
+            "text_secondary" => &mut self.text_secondary,
+            "text_muted" => &mut self.text_muted,
+            "segment_stroke" => &mut self.segment_stroke,
+            "svg_bg" => &mut self.svg_bg,
+            "svg_border" => &mut self.svg_border,
+            "center_circle_bg" => &mut self.center_circle_bg,
+            "ring_divider" => &mut self.ring_divider,
+            "now_highlight" => &mut self.now_highlight,
+            "button_primary_bg" => &mut self.button_primary_bg,
+            "button_primary_text" => &mut self.button_primary_text,
+            "button_secondary_bg" => &mut self.button_secondary_bg,
+            "input_border" => &mut self.input_border,
+            "input_bg" => &mut self.input_bg,
+            "success_bg" => &mut self.success_bg,
+            "success_border" => &mut self.success_border,
+            "success_text" => &mut self.success_text,
+            "success_text_dark" => &mut self.success_text_dark,
+            "warning_bg" => &mut self.warning_bg,
+            "warning_border" => &mut self.warning_border,
+            "warning_text" => &mut self.warning_text,
+            "warning_text_dark" => &mut self.warning_text_dark,
+            "meeting_essential" => &mut self.meeting_essential,
+            "meeting_non_essential" => &mut self.meeting_non_essential,
+            "working_hours" => &mut self.working_hours,
+            "working_hours_hatch" => &mut self.working_hours_hatch,
+            "now_line" => &mut self.now_line,
+            _ => return None,
+        })
+    }
+}
+
+impl Palette for ThemeColors {
+    fn color(&self, key: &str) -> Option<&str> {
+        ThemeColors::color(self, key)
+    }
+}
+
+/// Name of the CSS custom property carrying a color, e.g. `--tz-ring-1-text`
+pub fn css_var_name(key: &str) -> String {
+    format!("--tz-{}", key.replace('_', "-"))
+}
+
+/// Every color as a `var(--tz-...)` reference. Components style themselves
+/// with this, so switching theme or mode only rewrites the variables.
+pub static THEME_VARS: ThemeColors = ThemeColors {
+    background: "var(--tz-background)",
+    card_bg: "var(--tz-card-bg)",
+    card_border: "var(--tz-card-border)",
+    text_primary: "var(--tz-text-primary)",
+    text_secondary: "var(--tz-text-secondary)",
+    text_muted: "var(--tz-text-muted)",
+    segment_stroke: "var(--tz-segment-stroke)",
+    ring_defaults: ["var(--tz-ring-1)", "var(--tz-ring-2)", "var(--tz-ring-3)", "var(--tz-ring-4)", "var(--tz-ring-5)"],
+    ring_text_colors: ["var(--tz-ring-1-text)", "var(--tz-ring-2-text)", "var(--tz-ring-3-text)", "var(--tz-ring-4-text)", "var(--tz-ring-5-text)"],
+    svg_bg: "var(--tz-svg-bg)",
+    svg_border: "var(--tz-svg-border)",
+    center_circle_bg: "var(--tz-center-circle-bg)",
+    ring_divider: "var(--tz-ring-divider)",
+    now_highlight: "var(--tz-now-highlight)",
+    button_primary_bg: "var(--tz-button-primary-bg)",
+    button_primary_text: "var(--tz-button-primary-text)",
+    button_secondary_bg: "var(--tz-button-secondary-bg)",
+    input_border: "var(--tz-input-border)",
+    input_bg: "var(--tz-input-bg)",
+    success_bg: "var(--tz-success-bg)",
+    success_border: "var(--tz-success-border)",
+    success_text: "var(--tz-success-text)",
+    success_text_dark: "var(--tz-success-text-dark)",
+    warning_bg: "var(--tz-warning-bg)",
+    warning_border: "var(--tz-warning-border)",
+    warning_text: "var(--tz-warning-text)",
+    warning_text_dark: "var(--tz-warning-text-dark)",
+    meeting_essential: "var(--tz-meeting-essential)",
+    meeting_non_essential: "var(--tz-meeting-non-essential)",
+    working_hours: "var(--tz-working-hours)",
+    working_hours_hatch: "var(--tz-working-hours-hatch)",
+    now_line: "var(--tz-now-line)",
+};
+
+/// A stylesheet setting the palette's variables on `selector`
+pub fn theme_stylesheet(colors: &impl Palette, selector: &str) -> String {
+    let declarations: Vec<String> = COLOR_KEYS.iter()
+        .filter_map(|key| Some(format!("{}: {};", css_var_name(key), colors.color(key)?)))
+        .collect();
+    format!("{} {{ {} }}", selector, declarations.join(" "))
+}
+
+/// Colors of a built-in theme; custom themes resolve through `resolve_theme`
+/// and fall back to Minimalist here.
 pub fn get_theme(name: ThemeName, mode: Mode) -> &'static ThemeColors {
     match (name, mode) {
         (ThemeName::Minimalist, Mode::Light) => &MINIMALIST_LIGHT,
@@ -76,6 +346,11 @@ pub fn get_theme(name: ThemeName, mode: Mode) -> &'static ThemeColors {
         (ThemeName::Professional, Mode::Dark) => &PROFESSIONAL_DARK,
         (ThemeName::Playful, Mode::Light) => &PLAYFUL_LIGHT,
         (ThemeName::Playful, Mode::Dark) => &PLAYFUL_DARK,
+        (ThemeName::HighContrast, Mode::Light) => &HIGH_CONTRAST_LIGHT,
+        (ThemeName::HighContrast, Mode::Dark) => &HIGH_CONTRAST_DARK,
+        (ThemeName::ColorblindSafe, Mode::Light) => &COLORBLIND_SAFE_LIGHT,
+        (ThemeName::ColorblindSafe, Mode::Dark) => &COLORBLIND_SAFE_DARK,
+        (ThemeName::Custom(_), mode) => get_theme(ThemeName::Minimalist, mode),
     }
 }
 
@@ -109,6 +384,9 @@ static MINIMALIST_LIGHT: ThemeColors = ThemeColors {
     warning_text_dark: "#92400e",
     meeting_essential: "#22c55e",
     meeting_non_essential: "#ec4899",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#15803d",
+    now_line: "#ec4899",
 };
 
 static MINIMALIST_DARK: ThemeColors = ThemeColors {
@@ -141,6 +419,9 @@ static MINIMALIST_DARK: ThemeColors = ThemeColors {
     warning_text_dark: "#fcd34d",
     meeting_essential: "#22c55e",
     meeting_non_essential: "#ec4899",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#14532d",
+    now_line: "#ec4899",
 };
 
 static BOLD_LIGHT: ThemeColors = ThemeColors {
@@ -173,6 +454,9 @@ static BOLD_LIGHT: ThemeColors = ThemeColors {
     warning_text_dark: "#92400e",
     meeting_essential: "#84cc16",
     meeting_non_essential: "#ec4899",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#15803d",
+    now_line: "#ec4899",
 };
 
 static BOLD_DARK: ThemeColors = ThemeColors {
@@ -205,6 +489,9 @@ static BOLD_DARK: ThemeColors = ThemeColors {
     warning_text_dark: "#fcd34d",
     meeting_essential: "#ce9178",
     meeting_non_essential: "#c586c0",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#14532d",
+    now_line: "#ec4899",
 };
 
 static PROFESSIONAL_LIGHT: ThemeColors = ThemeColors {
@@ -237,6 +524,9 @@ static PROFESSIONAL_LIGHT: ThemeColors = ThemeColors {
     warning_text_dark: "#92400e",
     meeting_essential: "#0ea5e9",
     meeting_non_essential: "#f59e0b",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#15803d",
+    now_line: "#ec4899",
 };
 
 static PROFESSIONAL_DARK: ThemeColors = ThemeColors {
@@ -269,6 +559,9 @@ static PROFESSIONAL_DARK: ThemeColors = ThemeColors {
     warning_text_dark: "#fcd34d",
     meeting_essential: "#14b8a6",
     meeting_non_essential: "#f59e0b",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#14532d",
+    now_line: "#ec4899",
 };
 
 static PLAYFUL_LIGHT: ThemeColors = ThemeColors {
@@ -301,6 +594,9 @@ static PLAYFUL_LIGHT: ThemeColors = ThemeColors {
     warning_text_dark: "#9a3412",
     meeting_essential: "#059669",
     meeting_non_essential: "#a855f7",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#15803d",
+    now_line: "#ec4899",
 };
 
 static PLAYFUL_DARK: ThemeColors = ThemeColors {
@@ -333,4 +629,208 @@ static PLAYFUL_DARK: ThemeColors = ThemeColors {
     warning_text_dark: "#daa520",
     meeting_essential: "#569cd6",
     meeting_non_essential: "#bd63c5",
+    working_hours: "#22c55e",
+    working_hours_hatch: "#14532d",
+    now_line: "#ec4899",
+};
+
+static HIGH_CONTRAST_LIGHT: ThemeColors = ThemeColors {
+    background: "#ffffff",
+    card_bg: "#ffffff",
+    card_border: "#000000",
+    text_primary: "#000000",
+    text_secondary: "#000000",
+    text_muted: "#3d3d3d",
+    segment_stroke: "#000000",
+    ring_defaults: ["#ffffff", "#ffffff", "#ffffff", "#ffffff", "#ffffff"],
+    ring_text_colors: ["#000000", "#0000c8", "#000000", "#0000c8", "#000000"],
+    svg_bg: "#ffffff",
+    svg_border: "#000000",
+    center_circle_bg: "#ffffff",
+    ring_divider: "#000000",
+    now_highlight: "#0000c8",
+    button_primary_bg: "#000000",
+    button_primary_text: "#ffffff",
+    button_secondary_bg: "#ffffff",
+    input_border: "#000000",
+    input_bg: "#ffffff",
+    success_bg: "#ffffff",
+    success_border: "#000000",
+    success_text: "#000000",
+    success_text_dark: "#000000",
+    warning_bg: "#ffd400",
+    warning_border: "#000000",
+    warning_text: "#000000",
+    warning_text_dark: "#000000",
+    meeting_essential: "#000000",
+    meeting_non_essential: "#0000c8",
+    working_hours: "#ffd400",
+    working_hours_hatch: "#000000",
+    now_line: "#0000c8",
+};
+
+static HIGH_CONTRAST_DARK: ThemeColors = ThemeColors {
+    background: "#000000",
+    card_bg: "#000000",
+    card_border: "#ffffff",
+    text_primary: "#ffffff",
+    text_secondary: "#ffffff",
+    text_muted: "#c8c8c8",
+    segment_stroke: "#ffffff",
+    ring_defaults: ["#000000", "#000000", "#000000", "#000000", "#000000"],
+    ring_text_colors: ["#ffffff", "#ffd400", "#ffffff", "#ffd400", "#ffffff"],
+    svg_bg: "#000000",
+    svg_border: "#ffffff",
+    center_circle_bg: "#000000",
+    ring_divider: "#ffffff",
+    now_highlight: "#ffd400",
+    button_primary_bg: "#ffffff",
+    button_primary_text: "#000000",
+    button_secondary_bg: "#000000",
+    input_border: "#ffffff",
+    input_bg: "#000000",
+    success_bg: "#000000",
+    success_border: "#ffffff",
+    success_text: "#ffffff",
+    success_text_dark: "#ffffff",
+    warning_bg: "#000000",
+    warning_border: "#ffd400",
+    warning_text: "#ffd400",
+    warning_text_dark: "#ffd400",
+    meeting_essential: "#ffffff",
+    meeting_non_essential: "#ffd400",
+    working_hours: "#1a4fd6",
+    working_hours_hatch: "#ffffff",
+    now_line: "#ffd400",
+};
+
+// Okabe-Ito colors: blue and orange stay distinct under deuteranopia and
+// protanopia, where the usual green/pink pairing does not
+static COLORBLIND_SAFE_LIGHT: ThemeColors = ThemeColors {
+    background: "#ffffff",
+    card_bg: "#f7f7f7",
+    card_border: "#d0d0d0",
+    text_primary: "#1a1a1a",
+    text_secondary: "#404040",
+    text_muted: "#666666",
+    segment_stroke: "#ffffff",
+    ring_defaults: ["#ebebeb", "#ebebeb", "#ebebeb", "#ebebeb", "#ebebeb"],
+    ring_text_colors: ["#005a8c", "#a34400", "#1a1a1a", "#8a3b6b", "#005a8c"],
+    svg_bg: "#f7f7f7",
+    svg_border: "#d0d0d0",
+    center_circle_bg: "#ffffff",
+    ring_divider: "#ffffff",
+    now_highlight: "#d55e00",
+    button_primary_bg: "#005a8c",
+    button_primary_text: "#ffffff",
+    button_secondary_bg: "#ebebeb",
+    input_border: "#d0d0d0",
+    input_bg: "#ffffff",
+    success_bg: "#e6f2fa",
+    success_border: "#56b4e9",
+    success_text: "#005a8c",
+    success_text_dark: "#003f63",
+    warning_bg: "#fdf0dc",
+    warning_border: "#e69f00",
+    warning_text: "#a34400",
+    warning_text_dark: "#7a3300",
+    meeting_essential: "#0072b2",
+    meeting_non_essential: "#e69f00",
+    working_hours: "#56b4e9",
+    working_hours_hatch: "#0072b2",
+    now_line: "#d55e00",
+};
+
+static COLORBLIND_SAFE_DARK: ThemeColors = ThemeColors {
+    background: "#1a1a1a",
+    card_bg: "#242424",
+    card_border: "#444444",
+    text_primary: "#f0f0f0",
+    text_secondary: "#c8c8c8",
+    text_muted: "#9a9a9a",
+    segment_stroke: "#3a3a3a",
+    ring_defaults: ["#2c2c2c", "#2c2c2c", "#2c2c2c", "#2c2c2c", "#2c2c2c"],
+    ring_text_colors: ["#56b4e9", "#e69f00", "#f0e442", "#cc79a7", "#56b4e9"],
+    svg_bg: "#242424",
+    svg_border: "#444444",
+    center_circle_bg: "#1a1a1a",
+    ring_divider: "#3a3a3a",
+    now_highlight: "#e69f00",
+    button_primary_bg: "#0072b2",
+    button_primary_text: "#ffffff",
+    button_secondary_bg: "#3a3a3a",
+    input_border: "#444444",
+    input_bg: "#242424",
+    success_bg: "#10283a",
+    success_border: "#0072b2",
+    success_text: "#56b4e9",
+    success_text_dark: "#a6d8f5",
+    warning_bg: "#3a2a10",
+    warning_border: "#e69f00",
+    warning_text: "#e69f00",
+    warning_text_dark: "#f5c96b",
+    meeting_essential: "#56b4e9",
+    meeting_non_essential: "#e69f00",
+    working_hours: "#0072b2",
+    working_hours_hatch: "#56b4e9",
+    now_line: "#e69f00",
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::types::MAX_RINGS;
+
+    #[test]
+    fn test_css_variables() {
+        for key in COLOR_KEYS {
+            assert_eq!(THEME_VARS.color(key), Some(format!("var({})", css_var_name(key))).as_deref(), "{}", key);
+        }
+        let css = theme_stylesheet(get_theme(ThemeName::Bold, Mode::Light), ":root");
+        assert!(css.starts_with(":root { --tz-background: #ffffff;"));
+        assert!(css.contains("--tz-ring-2-text: #3b82f6;"));
+    }
+
+    #[test]
+    fn test_ring_colors_are_distinct() {
+        let colors: Vec<String> = (0..40).map(|slot| THEME_VARS.ring_text(slot)).collect();
+        assert_eq!(colors[1], "var(--tz-ring-2-text)");
+        assert_eq!(colors[5], "color-mix(in oklch, var(--tz-ring-1-text), var(--tz-ring-2-text) 50%)");
+        assert_eq!(colors[14], "color-mix(in oklch, var(--tz-ring-5-text), var(--tz-ring-1-text) 25%)");
+        assert_eq!(colors[15], "color-mix(in oklch, var(--tz-ring-1-text), var(--tz-ring-2-text) 75%)");
+        for (i, c) in colors.iter().enumerate() {
+            assert!(!colors[..i].contains(c), "slot {} repeats {}", i, c);
+        }
+    }
+
+    /// sRGB channels of a hex color or a `color-mix`, mixed in sRGB rather
+    /// than oklch: close enough to tell colors apart
+    fn approx_rgb(css: &str) -> [f64; 3] {
+        if let Some(hex) = css.strip_prefix('#') {
+            return [0, 2, 4].map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap() as f64);
+        }
+        let inner = css.strip_prefix("color-mix(in oklch, ").and_then(|c| c.strip_suffix(')')).unwrap();
+        let mut depth = 0;
+        let comma = inner.char_indices().find(|&(_, c)| {
+            depth += match c { '(' => 1, ')' => -1, _ => 0 };
+            c == ',' && depth == 0
+        }).unwrap().0;
+        let (second, share) = inner[comma + 2..].rsplit_once(' ').unwrap();
+        let share = share.trim_end_matches('%').parse::<f64>().unwrap() / 100.0;
+        let (a, b) = (approx_rgb(&inner[..comma]), approx_rgb(second));
+        [0, 1, 2].map(|i| a[i] * (1.0 - share) + b[i] * share)
+    }
+
+    #[test]
+    fn test_ring_fills_past_the_palette_differ() {
+        // Bold gives all five rings the same fill but distinct label colors
+        let bold = get_theme(ThemeName::Bold, Mode::Light);
+        let fills: Vec<[f64; 3]> = (RING_PALETTE..MAX_RINGS).map(|slot| approx_rgb(&bold.ring_fill(slot))).collect();
+        for (i, a) in fills.iter().enumerate() {
+            for b in &fills[..i] {
+                let distance = (0..3).map(|c| (a[c] - b[c]).abs()).fold(0.0, f64::max);
+                assert!(distance > 2.0, "slot {} matches an earlier slot", i + RING_PALETTE);
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use wasm_bindgen::JsValue;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HourCycle {
+    #[default]
+    H24,
+    H12,
+}
+
+impl HourCycle {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HourCycle::H24 => "24h",
+            HourCycle::H12 => "12h",
+        }
+    }
+
+    pub fn from_label(s: &str) -> Option<Self> {
+        match s {
+            "24h" => Some(HourCycle::H24),
+            "12h" => Some(HourCycle::H12),
+            _ => None,
+        }
+    }
+}
+
+/// How every time of day in the app is written. The locale-specific parts
+/// (day-period words, their position, the hour/minute separator) are read
+/// once from `Intl.DateTimeFormat` by [`TimeFormat::for_locale`]; the
+/// formatting itself is plain Rust so labels stay cheap to compute.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TimeFormat {
+    pub cycle: HourCycle,
+    pub am: String,
+    pub pm: String,
+    /// Day period written before the time, e.g. Japanese "午後3:00"
+    pub period_first: bool,
+    pub separator: String,
+}
+
+impl Default for TimeFormat {
+    fn default() -> Self {
+        Self::new(HourCycle::H24)
+    }
+}
+
+impl TimeFormat {
+    /// English conventions
+    pub fn new(cycle: HourCycle) -> Self {
+        Self {
+            cycle,
+            am: "AM".to_string(),
+            pm: "PM".to_string(),
+            period_first: false,
+            separator: ":".to_string(),
+        }
+    }
+
+    /// Split a fractional local hour into (hour 0-23, minute)
+    fn split(hour: f64) -> (u32, u32) {
+        let total_mins = (hour.rem_euclid(24.0) * 60.0).round() as u32 % (24 * 60);
+        (total_mins / 60, total_mins % 60)
+    }
+
+    fn with_period(&self, h24: u32, time: String, spacer: &str) -> String {
+        let period = if h24 < 12 { &self.am } else { &self.pm };
+        if self.period_first {
+            format!("{}{}", period, time)
+        } else {
+            format!("{}{}{}", time, spacer, period)
+        }
+    }
+
+    fn twelve(h24: u32) -> u32 {
+        match h24 % 12 {
+            0 => 12,
+            h => h,
+        }
+    }
+
+    /// Full time, e.g. "15:30" or "3:30 PM"
+    pub fn time(&self, hour: f64) -> String {
+        let (h, m) = Self::split(hour);
+        match self.cycle {
+            HourCycle::H24 => format!("{:02}{}{:02}", h, self.separator, m),
+            HourCycle::H12 => self.with_period(h, format!("{}{}{:02}", Self::twelve(h), self.separator, m), " "),
+        }
+    }
+
+    /// Time closing a range, where midnight is "24:00" on a 24-hour clock
+    /// and the 12-hour "12:00 AM" otherwise
+    pub fn end_time(&self, hour: f64) -> String {
+        if self.cycle == HourCycle::H24 && hour >= 24.0 {
+            format!("24{}00", self.separator)
+        } else {
+            self.time(hour)
+        }
+    }
+
+    /// Short dial label: whole hours without minutes, e.g. "15" or "3PM"
+    pub fn compact(&self, hour: f64) -> String {
+        let (h, m) = Self::split(hour);
+        let time = match (self.cycle, m) {
+            (HourCycle::H24, 0) => return format!("{:02}", h),
+            (HourCycle::H24, m) => return format!("{:02}{}{:02}", h, self.separator, m),
+            (HourCycle::H12, 0) => Self::twelve(h).to_string(),
+            (HourCycle::H12, m) => format!("{}{}{:02}", Self::twelve(h), self.separator, m),
+        };
+        self.with_period(h, time, "")
+    }
+
+    /// Read the conventions of `locale` (a BCP 47 tag) for `cycle`
+    pub fn for_locale(cycle: HourCycle, locale: &str) -> Self {
+        let mut format = Self::new(cycle);
+        let parts_at = |hour: f64, hour_cycle: &str| -> Vec<(String, String)> {
+            let options = js_sys::Object::new();
+            for (key, value) in [("hour", "numeric"), ("minute", "2-digit"), ("hourCycle", hour_cycle), ("timeZone", "UTC")] {
+                let _ = js_sys::Reflect::set(&options, &key.into(), &value.into());
+            }
+            let locales = js_sys::Array::of1(&locale.into());
+            let dtf = js_sys::Intl::DateTimeFormat::new(&locales, &options);
+            let date = js_sys::Date::new(&JsValue::from_f64(hour * 3_600_000.0));
+            dtf.format_to_parts(&date).iter().filter_map(|part| {
+                let get = |k: &str| js_sys::Reflect::get(&part, &k.into()).ok()?.as_string();
+                Some((get("type")?, get("value")?))
+            }).collect()
+        };
+        let position = |parts: &[(String, String)], kind: &str| parts.iter().position(|(t, _)| t == kind);
+
+        let morning = parts_at(3.0, "h12");
+        let afternoon = parts_at(15.0, "h12");
+        if let (Some(am), Some(pm)) = (position(&morning, "dayPeriod"), position(&afternoon, "dayPeriod")) {
+            format.am = morning[am].1.trim().to_string();
+            format.pm = afternoon[pm].1.trim().to_string();
+            format.period_first = position(&morning, "hour").is_some_and(|h| am < h);
+        }
+        let day = parts_at(15.0, "h23");
+        if let (Some(h), Some(m)) = (position(&day, "hour"), position(&day, "minute")) {
+            if m == h + 2 && day[h + 1].0 == "literal" {
+                format.separator = day[h + 1].1.clone();
+            }
+        }
+        format
+    }
+}
+
+/// The browser locale's preferred hour cycle
+pub fn browser_hour_cycle() -> HourCycle {
+    let options = js_sys::Object::new();
+    let _ = js_sys::Reflect::set(&options, &"hour".into(), &"numeric".into());
+    let resolved = js_sys::Intl::DateTimeFormat::new(&js_sys::Array::new(), &options).resolved_options();
+    let cycle = js_sys::Reflect::get(&resolved, &"hourCycle".into()).ok().and_then(|v| v.as_string());
+    match cycle.as_deref() {
+        Some("h11" | "h12") => HourCycle::H12,
+        _ => HourCycle::H24,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_24_hour() {
+        let f = TimeFormat::new(HourCycle::H24);
+        assert_eq!(f.time(15.5), "15:30");
+        assert_eq!(f.time(-1.0), "23:00");
+        assert_eq!(f.compact(9.0), "09");
+        assert_eq!(f.compact(5.75), "05:45");
+        assert_eq!(f.end_time(24.0), "24:00");
+        assert_eq!(f.end_time(18.0), "18:00");
+    }
+
+    #[test]
+    fn test_12_hour() {
+        let f = TimeFormat::new(HourCycle::H12);
+        assert_eq!(f.time(0.0), "12:00 AM");
+        assert_eq!(f.time(12.5), "12:30 PM");
+        assert_eq!(f.time(15.0), "3:00 PM");
+        assert_eq!(f.compact(15.0), "3PM");
+        assert_eq!(f.compact(9.5), "9:30AM");
+        assert_eq!(f.end_time(24.0), "12:00 AM");
+
+        let ja = TimeFormat { am: "午前".into(), pm: "午後".into(), period_first: true, ..f };
+        assert_eq!(ja.time(15.0), "午後3:00");
+        assert_eq!(ja.compact(3.0), "午前3");
+    }
+}
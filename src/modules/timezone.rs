@@ -1,4 +1,4 @@
-use crate::modules::types::{TimezoneEntry, WORK_START, WORK_END};
+use crate::modules::types::{Meeting, TimezoneEntry};
 
 /// Convert UTC hour to local time given UTC offset
 pub fn utc_to_local(utc_hour: f64, utc_offset: f64) -> f64 {
@@ -11,14 +11,16 @@ pub fn convert_between(hour: f64, from_offset: f64, to_offset: f64) -> f64 {
     utc_to_local(utc, to_offset)
 }
 
-/// Check if hour is within work hours (9:00-18:00)
-pub fn is_work_hour(hour: f64) -> bool {
-    hour >= WORK_START && hour < WORK_END
+/// Check if all timezones in the list are simultaneously in their working hours at the given UTC hour
+pub fn is_full_overlap_utc(utc_hour: f64, zones: &[TimezoneEntry]) -> bool {
+    zones.iter().all(|tz| tz.is_work_hour(utc_to_local(utc_hour, tz.utc_offset)))
 }
 
-/// Check if all timezones in the list are simultaneously in working hours at the given UTC hour
-pub fn is_full_overlap_utc(utc_hour: f64, zones: &[TimezoneEntry]) -> bool {
-    zones.iter().all(|tz| is_work_hour(utc_to_local(utc_hour, tz.utc_offset)))
+/// Move the given meetings to a new UTC hour, leaving the rest untouched
+pub fn reschedule_meetings(meetings: &mut [Meeting], ids: &[u32], utc_hour: u32) {
+    for m in meetings.iter_mut().filter(|m| ids.contains(&m.id)) {
+        m.utc_hour = utc_hour % 24;
+    }
 }
 
 /// Get current UTC hour as fractional (e.g. 14.5 = 14:30)
@@ -48,12 +50,33 @@ mod tests {
         assert_eq!(london_hour, 16.0);
     }
 
+    #[test]
+    fn test_reschedule_meetings() {
+        let meeting = |id, utc_hour| Meeting {
+            id,
+            utc_hour,
+            title: format!("m{}", id),
+            essential: false,
+            organizer_zone: "europe_london",
+        };
+        let mut meetings = vec![meeting(1, 15), meeting(2, 15), meeting(3, 9)];
+        reschedule_meetings(&mut meetings, &[1, 3], 16);
+        let hours: Vec<u32> = meetings.iter().map(|m| m.utc_hour).collect();
+        assert_eq!(hours, vec![16, 15, 16]);
+    }
+
     #[test]
     fn test_work_hours() {
-        assert!(is_work_hour(9.0));
-        assert!(is_work_hour(17.0));
-        assert!(is_work_hour(9.5));
-        assert!(!is_work_hour(8.99));
-        assert!(!is_work_hour(18.0));
+        // Database entries default to 9:00-18:00
+        let tz = crate::modules::timezone_db::zone_by_id("europe_london").unwrap();
+        assert!(tz.is_work_hour(9.0));
+        assert!(tz.is_work_hour(17.0));
+        assert!(tz.is_work_hour(9.5));
+        assert!(!tz.is_work_hour(8.99));
+        assert!(!tz.is_work_hour(18.0));
+
+        let custom = TimezoneEntry { work_start: 7.0, work_end: 15.5, ..tz.clone() };
+        assert!(custom.is_work_hour(7.0));
+        assert!(!custom.is_work_hour(15.5));
     }
 }
@@ -1,45 +1,83 @@
-use crate::modules::types::TimezoneEntry;
+use crate::modules::types::{TimezoneEntry, WORK_START, WORK_END};
 
 pub static TIMEZONE_DATABASE: &[TimezoneEntry] = &[
-    TimezoneEntry { id: "pacific_baker", name: "Baker Island", short_name: "BAKT", utc_offset: -12.0 },
-    TimezoneEntry { id: "pacific_samoa", name: "Pago Pago (SST)", short_name: "PPG", utc_offset: -11.0 },
-    TimezoneEntry { id: "pacific_honolulu", name: "Honolulu (HST)", short_name: "HNL", utc_offset: -10.0 },
-    TimezoneEntry { id: "pacific_marquesas", name: "Marquesas Islands", short_name: "MART", utc_offset: -9.5 },
-    TimezoneEntry { id: "america_anchorage", name: "Anchorage (AKST)", short_name: "ANC", utc_offset: -9.0 },
-    TimezoneEntry { id: "america_los_angeles", name: "Los Angeles (PST)", short_name: "LAX", utc_offset: -8.0 },
-    TimezoneEntry { id: "america_denver", name: "Denver (MST)", short_name: "DEN", utc_offset: -7.0 },
-    TimezoneEntry { id: "america_chicago", name: "Dallas (CST)", short_name: "DAL", utc_offset: -6.0 },
-    TimezoneEntry { id: "america_new_york", name: "New York (EST)", short_name: "NYC", utc_offset: -5.0 },
-    TimezoneEntry { id: "america_caracas", name: "Caracas (VET)", short_name: "CCS", utc_offset: -4.0 },
-    TimezoneEntry { id: "america_st_johns", name: "St. John's (NST)", short_name: "YYT", utc_offset: -3.5 },
-    TimezoneEntry { id: "america_sao_paulo", name: "São Paulo (BRT)", short_name: "GRU", utc_offset: -3.0 },
-    TimezoneEntry { id: "atlantic_south_georgia", name: "South Georgia", short_name: "GSI", utc_offset: -2.0 },
-    TimezoneEntry { id: "atlantic_azores", name: "Azores (AZOT)", short_name: "AZO", utc_offset: -1.0 },
-    TimezoneEntry { id: "europe_london", name: "London (GMT)", short_name: "LON", utc_offset: 0.0 },
-    TimezoneEntry { id: "europe_paris", name: "Paris (CET)", short_name: "PAR", utc_offset: 1.0 },
-    TimezoneEntry { id: "europe_berlin", name: "Berlin (CET)", short_name: "BER", utc_offset: 1.0 },
-    TimezoneEntry { id: "africa_cairo", name: "Cairo (EET)", short_name: "CAI", utc_offset: 2.0 },
-    TimezoneEntry { id: "europe_moscow", name: "Moscow (MSK)", short_name: "MOW", utc_offset: 3.0 },
-    TimezoneEntry { id: "asia_tehran", name: "Tehran (IRST)", short_name: "THR", utc_offset: 3.5 },
-    TimezoneEntry { id: "asia_dubai", name: "Dubai (GST)", short_name: "DXB", utc_offset: 4.0 },
-    TimezoneEntry { id: "asia_kabul", name: "Kabul (AFT)", short_name: "KBL", utc_offset: 4.5 },
-    TimezoneEntry { id: "asia_karachi", name: "Karachi (PKT)", short_name: "KHI", utc_offset: 5.0 },
-    TimezoneEntry { id: "asia_kolkata", name: "India (IST)", short_name: "DEL", utc_offset: 5.5 },
-    TimezoneEntry { id: "asia_kathmandu", name: "Kathmandu (NPT)", short_name: "KTM", utc_offset: 5.75 },
-    TimezoneEntry { id: "asia_dhaka", name: "Dhaka (BST)", short_name: "DAC", utc_offset: 6.0 },
-    TimezoneEntry { id: "asia_yangon", name: "Yangon (MMT)", short_name: "RGN", utc_offset: 6.5 },
-    TimezoneEntry { id: "asia_bangkok", name: "Bangkok (ICT)", short_name: "BKK", utc_offset: 7.0 },
-    TimezoneEntry { id: "asia_shanghai", name: "Shanghai (CST)", short_name: "SHA", utc_offset: 8.0 },
-    TimezoneEntry { id: "asia_hong_kong", name: "Hong Kong (HKT)", short_name: "HKG", utc_offset: 8.0 },
-    TimezoneEntry { id: "asia_singapore", name: "Singapore (SGT)", short_name: "SIN", utc_offset: 8.0 },
-    TimezoneEntry { id: "australia_eucla", name: "Eucla (ACWST)", short_name: "EUCL", utc_offset: 8.75 },
-    TimezoneEntry { id: "asia_tokyo", name: "Tokyo (JST)", short_name: "TYO", utc_offset: 9.0 },
-    TimezoneEntry { id: "australia_darwin", name: "Darwin (ACST)", short_name: "DRW", utc_offset: 9.5 },
-    TimezoneEntry { id: "australia_sydney", name: "Sydney (AEST)", short_name: "SYD", utc_offset: 10.0 },
-    TimezoneEntry { id: "australia_lhi", name: "Lord Howe Island", short_name: "LDH", utc_offset: 10.5 },
-    TimezoneEntry { id: "pacific_noumea", name: "Nouméa (NCT)", short_name: "NOU", utc_offset: 11.0 },
-    TimezoneEntry { id: "pacific_auckland", name: "Auckland (NZST)", short_name: "AKL", utc_offset: 12.0 },
-    TimezoneEntry { id: "pacific_chatham", name: "Chatham Islands", short_name: "CHT", utc_offset: 12.75 },
-    TimezoneEntry { id: "pacific_tongatapu", name: "Tonga (TOT)", short_name: "TBU", utc_offset: 13.0 },
-    TimezoneEntry { id: "pacific_kiritimati", name: "Kiritimati (LINT)", short_name: "CXI", utc_offset: 14.0 },
+    TimezoneEntry { id: "pacific_baker", iana: "Etc/GMT+12", name: "Baker Island", short_name: "BAKT", utc_offset: -12.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_samoa", iana: "Pacific/Pago_Pago", name: "Pago Pago (SST)", short_name: "PPG", utc_offset: -11.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_honolulu", iana: "Pacific/Honolulu", name: "Honolulu (HST)", short_name: "HNL", utc_offset: -10.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_marquesas", iana: "Pacific/Marquesas", name: "Marquesas Islands", short_name: "MART", utc_offset: -9.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_anchorage", iana: "America/Anchorage", name: "Anchorage (AKST)", short_name: "ANC", utc_offset: -9.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_los_angeles", iana: "America/Los_Angeles", name: "Los Angeles (PST)", short_name: "LAX", utc_offset: -8.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_denver", iana: "America/Denver", name: "Denver (MST)", short_name: "DEN", utc_offset: -7.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_chicago", iana: "America/Chicago", name: "Dallas (CST)", short_name: "DAL", utc_offset: -6.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_new_york", iana: "America/New_York", name: "New York (EST)", short_name: "NYC", utc_offset: -5.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_caracas", iana: "America/Caracas", name: "Caracas (VET)", short_name: "CCS", utc_offset: -4.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_st_johns", iana: "America/St_Johns", name: "St. John's (NST)", short_name: "YYT", utc_offset: -3.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "america_sao_paulo", iana: "America/Sao_Paulo", name: "São Paulo (BRT)", short_name: "GRU", utc_offset: -3.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "atlantic_south_georgia", iana: "Atlantic/South_Georgia", name: "South Georgia", short_name: "GSI", utc_offset: -2.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "atlantic_azores", iana: "Atlantic/Azores", name: "Azores (AZOT)", short_name: "AZO", utc_offset: -1.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "europe_london", iana: "Europe/London", name: "London (GMT)", short_name: "LON", utc_offset: 0.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "europe_paris", iana: "Europe/Paris", name: "Paris (CET)", short_name: "PAR", utc_offset: 1.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "europe_berlin", iana: "Europe/Berlin", name: "Berlin (CET)", short_name: "BER", utc_offset: 1.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "africa_cairo", iana: "Africa/Cairo", name: "Cairo (EET)", short_name: "CAI", utc_offset: 2.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "europe_moscow", iana: "Europe/Moscow", name: "Moscow (MSK)", short_name: "MOW", utc_offset: 3.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_tehran", iana: "Asia/Tehran", name: "Tehran (IRST)", short_name: "THR", utc_offset: 3.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_dubai", iana: "Asia/Dubai", name: "Dubai (GST)", short_name: "DXB", utc_offset: 4.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_kabul", iana: "Asia/Kabul", name: "Kabul (AFT)", short_name: "KBL", utc_offset: 4.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_karachi", iana: "Asia/Karachi", name: "Karachi (PKT)", short_name: "KHI", utc_offset: 5.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_kolkata", iana: "Asia/Kolkata", name: "India (IST)", short_name: "DEL", utc_offset: 5.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_kathmandu", iana: "Asia/Kathmandu", name: "Kathmandu (NPT)", short_name: "KTM", utc_offset: 5.75, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_dhaka", iana: "Asia/Dhaka", name: "Dhaka (BST)", short_name: "DAC", utc_offset: 6.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_yangon", iana: "Asia/Yangon", name: "Yangon (MMT)", short_name: "RGN", utc_offset: 6.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_bangkok", iana: "Asia/Bangkok", name: "Bangkok (ICT)", short_name: "BKK", utc_offset: 7.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_shanghai", iana: "Asia/Shanghai", name: "Shanghai (CST)", short_name: "SHA", utc_offset: 8.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_hong_kong", iana: "Asia/Hong_Kong", name: "Hong Kong (HKT)", short_name: "HKG", utc_offset: 8.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_singapore", iana: "Asia/Singapore", name: "Singapore (SGT)", short_name: "SIN", utc_offset: 8.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "australia_eucla", iana: "Australia/Eucla", name: "Eucla (ACWST)", short_name: "EUCL", utc_offset: 8.75, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "asia_tokyo", iana: "Asia/Tokyo", name: "Tokyo (JST)", short_name: "TYO", utc_offset: 9.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "australia_darwin", iana: "Australia/Darwin", name: "Darwin (ACST)", short_name: "DRW", utc_offset: 9.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "australia_sydney", iana: "Australia/Sydney", name: "Sydney (AEST)", short_name: "SYD", utc_offset: 10.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "australia_lhi", iana: "Australia/Lord_Howe", name: "Lord Howe Island", short_name: "LDH", utc_offset: 10.5, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_noumea", iana: "Pacific/Noumea", name: "Nouméa (NCT)", short_name: "NOU", utc_offset: 11.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_auckland", iana: "Pacific/Auckland", name: "Auckland (NZST)", short_name: "AKL", utc_offset: 12.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_chatham", iana: "Pacific/Chatham", name: "Chatham Islands", short_name: "CHT", utc_offset: 12.75, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_tongatapu", iana: "Pacific/Tongatapu", name: "Tonga (TOT)", short_name: "TBU", utc_offset: 13.0, work_start: WORK_START, work_end: WORK_END },
+    TimezoneEntry { id: "pacific_kiritimati", iana: "Pacific/Kiritimati", name: "Kiritimati (LINT)", short_name: "CXI", utc_offset: 14.0, work_start: WORK_START, work_end: WORK_END },
 ];
+
+/// Look up a database entry by its stable id
+pub fn zone_by_id(id: &str) -> Option<&'static TimezoneEntry> {
+    TIMEZONE_DATABASE.iter().find(|tz| tz.id == id)
+}
+
+/// Database entry for an IANA zone name. A zone the database lacks maps to
+/// the nearest entry by standard offset (`standard_offset` hours), preferring
+/// one in the same area such as "Europe/", so its id resolves in any browser
+/// and in shared or exported configurations.
+pub fn zone_for_iana(iana: &str, standard_offset: f64) -> &'static TimezoneEntry {
+    if let Some(tz) = TIMEZONE_DATABASE.iter().find(|tz| tz.iana == iana) {
+        return tz;
+    }
+    let area = |name: &str| name.split_once('/').map(|(area, _)| area.to_string());
+    TIMEZONE_DATABASE.iter()
+        .min_by(|a, b| {
+            let key = |tz: &TimezoneEntry| ((tz.utc_offset - standard_offset).abs(), area(tz.iana) != area(iana));
+            let ((da, oa), (db, ob)) = (key(a), key(b));
+            da.total_cmp(&db).then(oa.cmp(&ob))
+        })
+        .unwrap_or(&TIMEZONE_DATABASE[0])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zone_for_iana() {
+        assert_eq!(zone_for_iana("Europe/Paris", 1.0).id, "europe_paris");
+        // Not in the database: the nearest entry, from the same area when possible
+        assert_eq!(zone_for_iana("Europe/Madrid", 1.0).id, "europe_paris");
+        assert_eq!(zone_for_iana("Africa/Lagos", 1.0).id, "europe_paris");
+        assert_eq!(zone_for_iana("America/Phoenix", -7.0).id, "america_denver");
+        assert_eq!(zone_for_iana("Asia/Colombo", 5.5).id, "asia_kolkata");
+    }
+}
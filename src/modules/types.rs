@@ -4,32 +4,250 @@ pub struct Meeting {
     pub utc_hour: u32,
     pub title: String,
     pub essential: bool,
+    /// Zone id of the organizer; the meeting's marker is drawn on that ring.
+    pub organizer_zone: &'static str,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct TimezoneEntry {
     pub id: &'static str,
+    /// IANA zone name, used to recognise the browser's zone
+    pub iana: &'static str,
     pub name: &'static str,
     pub short_name: &'static str,
     pub utc_offset: f64,
+    /// Local working hours for this zone (defaults to `WORK_START..WORK_END`)
+    pub work_start: f64,
+    pub work_end: f64,
+}
+
+impl TimezoneEntry {
+    /// Check if a local hour falls within this zone's working hours
+    pub fn is_work_hour(&self, hour: f64) -> bool {
+        (self.work_start..self.work_end).contains(&hour)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ActiveTimezones {
-    pub zones: Vec<TimezoneEntry>,
+    zones: Vec<TimezoneEntry>,
+    /// Palette slot of each zone, parallel to `zones`. A zone gets the lowest
+    /// free slot when it is added and keeps it until removed, so its ring's
+    /// color follows it through reorders and other zones coming and going.
+    colors: Vec<usize>,
 }
 
 impl Default for ActiveTimezones {
     fn default() -> Self {
         use crate::modules::timezone_db::TIMEZONE_DATABASE;
-        Self {
-            zones: vec![
-                TIMEZONE_DATABASE.iter().find(|t| t.id == "america_chicago").unwrap().clone(),
-                TIMEZONE_DATABASE.iter().find(|t| t.id == "america_new_york").unwrap().clone(),
-                TIMEZONE_DATABASE.iter().find(|t| t.id == "europe_london").unwrap().clone(),
-            ],
+        Self::new(vec![
+            TIMEZONE_DATABASE.iter().find(|t| t.id == "america_chicago").unwrap().clone(),
+            TIMEZONE_DATABASE.iter().find(|t| t.id == "america_new_york").unwrap().clone(),
+            TIMEZONE_DATABASE.iter().find(|t| t.id == "europe_london").unwrap().clone(),
+        ])
+    }
+}
+
+impl ActiveTimezones {
+    /// Zones colored by position, as rings were before colors were stored
+    pub fn new(zones: Vec<TimezoneEntry>) -> Self {
+        Self::with_colors(zones.into_iter().map(|tz| (tz, None)).collect())
+    }
+
+    /// Zones with their stored palette slots. Missing or duplicate slots are
+    /// replaced by the lowest free one, in ring order.
+    pub fn with_colors(zones: Vec<(TimezoneEntry, Option<usize>)>) -> Self {
+        let mut kept: Vec<Option<usize>> = Vec::with_capacity(zones.len());
+        for (_, color) in &zones {
+            kept.push(color.filter(|c| !kept.contains(&Some(*c))));
         }
+        let mut active = Self { zones: Vec::new(), colors: Vec::new() };
+        for ((tz, _), color) in zones.into_iter().zip(&kept) {
+            let color = color.unwrap_or_else(|| {
+                (0..).find(|c| !active.colors.contains(c) && !kept.contains(&Some(*c))).unwrap_or(0)
+            });
+            active.zones.push(tz);
+            active.colors.push(color);
+        }
+        active
+    }
+
+    /// The default rings with `local` moved to (or added as) the first ring
+    pub fn with_local_first(local: &TimezoneEntry) -> Self {
+        let mut zones = vec![local.clone()];
+        zones.extend(Self::default().zones.into_iter().filter(|tz| tz.id != local.id));
+        zones.truncate(MAX_RINGS);
+        Self::new(zones)
+    }
+
+    /// The zones in ring order, outermost first
+    pub fn zones(&self) -> &[TimezoneEntry] {
+        &self.zones
     }
+
+    /// Ring index a meeting's marker belongs on: its organizer's ring,
+    /// or the outermost ring when that zone is no longer active.
+    pub fn meeting_ring(&self, meeting: &Meeting) -> usize {
+        self.zones.iter().position(|z| z.id == meeting.organizer_zone).unwrap_or(0)
+    }
+
+    /// Palette slot of the ring at `index`
+    pub fn palette_index(&self, index: usize) -> usize {
+        self.colors.get(index).copied().unwrap_or(index)
+    }
+
+    /// Add a ring on the inside with the lowest palette slot not in use
+    pub fn push(&mut self, tz: TimezoneEntry) {
+        self.colors.truncate(self.zones.len());
+        let color = (0..).find(|c| !self.colors.contains(c)).unwrap_or(0);
+        self.zones.push(tz);
+        self.colors.push(color);
+    }
+
+    /// Add a ring on the inside keeping `color` when no other ring has it,
+    /// otherwise with the lowest free slot like [`push`](Self::push)
+    pub fn push_with_color(&mut self, tz: TimezoneEntry, color: usize) {
+        self.colors.truncate(self.zones.len());
+        if self.colors.contains(&color) {
+            self.push(tz);
+        } else {
+            self.zones.push(tz);
+            self.colors.push(color);
+        }
+    }
+
+    /// Remove the ring at `index`, freeing its palette slot
+    pub fn remove(&mut self, index: usize) {
+        if index < self.zones.len() {
+            self.zones.remove(index);
+            if index < self.colors.len() {
+                self.colors.remove(index);
+            }
+        }
+    }
+
+    /// Put `tz` on the ring at `index`. The old zone's palette slot is freed
+    /// and `tz` gets the lowest free one, as if removed and added in place.
+    pub fn replace(&mut self, index: usize, tz: TimezoneEntry) {
+        if index < self.zones.len() {
+            self.colors.truncate(self.zones.len());
+            let color = (0..).find(|c| self.colors.iter().enumerate().all(|(i, used)| i == index || used != c)).unwrap_or(0);
+            self.zones[index] = tz;
+            if let Some(slot) = self.colors.get_mut(index) {
+                *slot = color;
+            }
+        }
+    }
+
+    /// Set the working hours of the ring at `index`
+    pub fn set_work_hours(&mut self, index: usize, start: f64, end: f64) {
+        if let Some(tz) = self.zones.get_mut(index) {
+            tz.work_start = start;
+            tz.work_end = end;
+        }
+    }
+
+    /// Move the ring at `from` to position `to`, shifting the ones between
+    pub fn move_zone(&mut self, from: usize, to: usize) {
+        if from < self.zones.len() && to < self.zones.len() {
+            let tz = self.zones.remove(from);
+            self.zones.insert(to, tz);
+            if from < self.colors.len() && to < self.colors.len() {
+                let color = self.colors.remove(from);
+                self.colors.insert(to, color);
+            }
+        }
+    }
+
+    /// Exchange the rings at `a` and `b`, each keeping its color
+    pub fn swap(&mut self, a: usize, b: usize) {
+        self.zones.swap(a, b);
+        if a < self.colors.len() && b < self.colors.len() {
+            self.colors.swap(a, b);
+        }
+    }
+
+    /// Resolve `reference`; a zone that is no longer active falls back to the
+    /// first ring. `local_offset` is the browser's current UTC offset in hours.
+    pub fn reference(&self, reference: ReferenceZone, local_offset: f64) -> Reference {
+        let first = &self.zones[0];
+        let zone = |tz: &TimezoneEntry| Reference { utc_offset: tz.utc_offset, short_name: tz.short_name, zone_id: Some(tz.id) };
+        match reference {
+            ReferenceZone::FirstRing => zone(first),
+            ReferenceZone::Zone(id) => zone(self.zones.iter().find(|tz| tz.id == id).unwrap_or(first)),
+            ReferenceZone::Utc => Reference { utc_offset: 0.0, short_name: "UTC", zone_id: None },
+            ReferenceZone::Local => Reference { utc_offset: local_offset, short_name: "Local", zone_id: None },
+        }
+    }
+}
+
+/// Zone the dial is anchored to: its hours sit at fixed positions around the
+/// face, and summaries and meeting times are labelled in it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReferenceZone {
+    /// Whichever zone is on the outer ring
+    #[default]
+    FirstRing,
+    /// A specific active zone by id, wherever its ring is
+    Zone(&'static str),
+    Utc,
+    /// The browser's own offset
+    Local,
+}
+
+impl ReferenceZone {
+    /// Stable key for storage and config files: "first", "utc", "local" or a zone id
+    pub fn key(&self) -> &'static str {
+        match self {
+            ReferenceZone::FirstRing => "first",
+            ReferenceZone::Zone(id) => id,
+            ReferenceZone::Utc => "utc",
+            ReferenceZone::Local => "local",
+        }
+    }
+
+    pub fn from_key(key: &str) -> Option<Self> {
+        use crate::modules::timezone_db::zone_by_id;
+        match key {
+            "first" => Some(ReferenceZone::FirstRing),
+            "utc" => Some(ReferenceZone::Utc),
+            "local" => Some(ReferenceZone::Local),
+            id => zone_by_id(id).map(|tz| ReferenceZone::Zone(tz.id)),
+        }
+    }
+}
+
+/// How the active zones are laid out: rings on a dial, rows on a timeline,
+/// or a week of overlap in the reference zone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClockLayout {
+    #[default]
+    Dial,
+    Timeline,
+    Week,
+}
+
+impl ClockLayout {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ClockLayout::Dial => "Dial",
+            ClockLayout::Timeline => "Timeline",
+            ClockLayout::Week => "Week",
+        }
+    }
+
+    pub fn from_label(label: &str) -> Option<Self> {
+        [ClockLayout::Dial, ClockLayout::Timeline, ClockLayout::Week].into_iter().find(|l| l.label() == label)
+    }
+}
+
+/// A reference zone resolved against the active rings
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reference {
+    pub utc_offset: f64,
+    pub short_name: &'static str,
+    /// The database zone behind it, if any
+    pub zone_id: Option<&'static str>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -38,7 +256,18 @@ pub struct SelectedSlot {
     pub local_hours: Vec<f64>,
 }
 
-#[derive(Debug, Clone, Copy)]
+impl SelectedSlot {
+    /// Slot for a UTC hour with the local hour of every active zone.
+    pub fn at(utc_hour: u32, zones: &ActiveTimezones) -> Self {
+        use crate::modules::timezone::utc_to_local;
+        Self {
+            utc_hour,
+            local_hours: zones.zones.iter().map(|tz| utc_to_local(utc_hour as f64, tz.utc_offset)).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RingGeometry {
     pub outer_r: f64,
     pub inner_r: f64,
@@ -54,4 +283,84 @@ pub const WORK_END: f64 = 18.0;
 
 pub const RING_GAP: f64 = 4.0;
 pub const CENTER_GAP: f64 = 16.0;
-pub const MAX_RINGS: usize = 5;
+/// Most zones at once. The dial has a fixed radius, so more rings would be
+/// thinner than their hour labels.
+pub const MAX_RINGS: usize = 12;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn colors(z: &ActiveTimezones) -> Vec<(&str, usize)> {
+        z.zones.iter().enumerate().map(|(i, tz)| (tz.id, z.palette_index(i))).collect()
+    }
+
+    #[test]
+    fn test_reorder_keeps_colors() {
+        let mut zones = ActiveTimezones::default();
+        assert_eq!(colors(&zones), [("america_chicago", 0), ("america_new_york", 1), ("europe_london", 2)]);
+        zones.move_zone(2, 0);
+        assert_eq!(colors(&zones), [("europe_london", 2), ("america_chicago", 0), ("america_new_york", 1)]);
+        zones.swap(0, 2);
+        assert_eq!(colors(&zones), [("america_new_york", 1), ("america_chicago", 0), ("europe_london", 2)]);
+        zones.move_zone(0, 7);
+        assert_eq!(zones.zones[0].id, "america_new_york");
+    }
+
+    #[test]
+    fn test_add_and_remove_keep_colors() {
+        use crate::modules::timezone_db::zone_by_id;
+        let mut zones = ActiveTimezones::default();
+        // A new zone never recolors the existing ones, wherever its id sorts
+        zones.push(zone_by_id("asia_tokyo").unwrap().clone());
+        assert_eq!(colors(&zones), [("america_chicago", 0), ("america_new_york", 1), ("europe_london", 2), ("asia_tokyo", 3)]);
+
+        // Removing one leaves the others alone and frees its slot for the next zone
+        zones.remove(1);
+        assert_eq!(colors(&zones), [("america_chicago", 0), ("europe_london", 2), ("asia_tokyo", 3)]);
+        zones.push(zone_by_id("europe_paris").unwrap().clone());
+        assert_eq!(colors(&zones)[3], ("europe_paris", 1));
+    }
+
+    #[test]
+    fn test_replace_frees_color() {
+        use crate::modules::timezone_db::zone_by_id;
+        let mut zones = ActiveTimezones::default();
+        zones.remove(0);
+        // The new zone takes the lowest free slot, not the one it replaces
+        zones.replace(1, zone_by_id("asia_tokyo").unwrap().clone());
+        assert_eq!(colors(&zones), [("america_new_york", 1), ("asia_tokyo", 0)]);
+        zones.push(zone_by_id("europe_paris").unwrap().clone());
+        assert_eq!(colors(&zones)[2], ("europe_paris", 2));
+    }
+
+    #[test]
+    fn test_stored_colors() {
+        use crate::modules::timezone_db::zone_by_id;
+        let tz = |id| zone_by_id(id).unwrap().clone();
+        // Saved before colors were stored: slots follow the ring order
+        let zones = ActiveTimezones::new(vec![tz("europe_london"), tz("america_chicago")]);
+        assert_eq!(colors(&zones), [("europe_london", 0), ("america_chicago", 1)]);
+
+        // Duplicates and gaps are filled without disturbing valid slots
+        let zones = ActiveTimezones::with_colors(vec![(tz("europe_london"), Some(4)), (tz("america_chicago"), Some(4)), (tz("asia_tokyo"), None)]);
+        assert_eq!(colors(&zones), [("europe_london", 4), ("america_chicago", 0), ("asia_tokyo", 1)]);
+    }
+
+    #[test]
+    fn test_reference_resolution() {
+        let zones = ActiveTimezones::default();
+        let offset = |r| zones.reference(r, 5.5).utc_offset;
+        assert_eq!(offset(ReferenceZone::FirstRing), -6.0);
+        assert_eq!(offset(ReferenceZone::Zone("europe_london")), 0.0);
+        assert_eq!(offset(ReferenceZone::Utc), 0.0);
+        assert_eq!(offset(ReferenceZone::Local), 5.5);
+        // Removed zones fall back to the first ring
+        assert_eq!(offset(ReferenceZone::Zone("asia_tokyo")), -6.0);
+
+        for r in [ReferenceZone::FirstRing, ReferenceZone::Zone("asia_tokyo"), ReferenceZone::Utc, ReferenceZone::Local] {
+            assert_eq!(ReferenceZone::from_key(r.key()), Some(r));
+        }
+        assert_eq!(ReferenceZone::from_key("mars_olympus"), None);
+    }
+}
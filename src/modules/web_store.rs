@@ -0,0 +1,171 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use crate::modules::store::{MemoryStore, StateStore, StoreError};
+
+const IDB_NAME: &str = "tz-clock";
+const IDB_STORE: &str = "state";
+const PROBE_KEY: &str = "tz-clock-probe";
+
+fn store_error(err: &JsValue) -> StoreError {
+    match err.dyn_ref::<web_sys::DomException>().map(|e| e.name()) {
+        Some(name) if name == "QuotaExceededError" || name == "NS_ERROR_DOM_QUOTA_REACHED" => StoreError::QuotaExceeded,
+        Some(name) if name == "SecurityError" => StoreError::Unavailable,
+        Some(name) => StoreError::Failed(name),
+        None => StoreError::Failed(err.as_string().unwrap_or_else(|| "unknown error".to_string())),
+    }
+}
+
+/// `window.localStorage`
+pub struct LocalStore {
+    storage: web_sys::Storage,
+}
+
+impl LocalStore {
+    pub fn open() -> Result<Self, StoreError> {
+        let window = web_sys::window().ok_or(StoreError::Unavailable)?;
+        let storage = window.local_storage().map_err(|e| store_error(&e))?.ok_or(StoreError::Unavailable)?;
+        Ok(Self { storage })
+    }
+
+    /// Write and remove a probe key. Some private modes hand out a storage
+    /// object that rejects every write with a SecurityError.
+    fn probe(&self) -> Result<(), StoreError> {
+        self.storage.set_item(PROBE_KEY, "1").map_err(|e| store_error(&e))?;
+        let _ = self.storage.remove_item(PROBE_KEY);
+        Ok(())
+    }
+}
+
+impl StateStore for LocalStore {
+    fn get(&self, key: &str) -> Result<Option<String>, StoreError> {
+        self.storage.get_item(key).map_err(|e| store_error(&e))
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StoreError> {
+        self.storage.set_item(key, value).map_err(|e| store_error(&e))
+    }
+}
+
+type ErrorHandler = Rc<RefCell<Option<Rc<dyn Fn(StoreError)>>>>;
+
+/// IndexedDB, read fully into memory when opened so reads stay synchronous.
+/// Writes update the cache at once and reach the database in the background;
+/// failures are reported through `on_async_error`.
+pub struct IndexedDbStore {
+    db: web_sys::IdbDatabase,
+    cache: RefCell<HashMap<String, String>>,
+    error_handler: ErrorHandler,
+    on_abort: Closure<dyn FnMut(web_sys::Event)>,
+}
+
+/// Resolve with the request's result once it succeeds or fails
+async fn request_done(request: &web_sys::IdbRequest) -> Result<JsValue, StoreError> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        let req = request.clone();
+        let on_success = Closure::once_into_js(move || {
+            let _ = resolve.call1(&JsValue::NULL, &req.result().unwrap_or(JsValue::UNDEFINED));
+        });
+        let req = request.clone();
+        let on_error = Closure::once_into_js(move || {
+            let err = req.error().ok().flatten().map(JsValue::from).unwrap_or(JsValue::UNDEFINED);
+            let _ = reject.call1(&JsValue::NULL, &err);
+        });
+        request.set_onsuccess(Some(on_success.unchecked_ref()));
+        request.set_onerror(Some(on_error.unchecked_ref()));
+    });
+    JsFuture::from(promise).await.map_err(|e| store_error(&e))
+}
+
+impl IndexedDbStore {
+    pub async fn open() -> Result<Self, StoreError> {
+        let js_err = |e: JsValue| store_error(&e);
+        let factory = web_sys::window()
+            .ok_or(StoreError::Unavailable)?
+            .indexed_db().map_err(js_err)?
+            .ok_or(StoreError::Unavailable)?;
+        let request = factory.open_with_u32(IDB_NAME, 1).map_err(js_err)?;
+        let upgrade_request = request.clone();
+        let on_upgrade = Closure::<dyn FnMut()>::new(move || {
+            if let Ok(db) = upgrade_request.result().and_then(|r| r.dyn_into::<web_sys::IdbDatabase>()) {
+                let _ = db.create_object_store(IDB_STORE);
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+        let db: web_sys::IdbDatabase = request_done(&request).await?.unchecked_into();
+        drop(on_upgrade);
+
+        // Issue both reads before awaiting so the transaction stays open for the second
+        let objects = db.transaction_with_str(IDB_STORE)
+            .and_then(|tx| tx.object_store(IDB_STORE))
+            .map_err(js_err)?;
+        let keys_request = objects.get_all_keys().map_err(js_err)?;
+        let values_request = objects.get_all().map_err(js_err)?;
+        let keys = js_sys::Array::from(&request_done(&keys_request).await?);
+        let values = js_sys::Array::from(&request_done(&values_request).await?);
+        let cache = keys.iter().zip(values.iter())
+            .filter_map(|(k, v)| Some((k.as_string()?, v.as_string()?)))
+            .collect();
+
+        let error_handler: ErrorHandler = Rc::default();
+        let handler = error_handler.clone();
+        let on_abort = Closure::<dyn FnMut(web_sys::Event)>::new(move |ev: web_sys::Event| {
+            let error = ev.target()
+                .and_then(|t| t.dyn_into::<web_sys::IdbTransaction>().ok())
+                .and_then(|tx| tx.error())
+                .map_or_else(|| StoreError::Failed("write aborted".to_string()), |e| store_error(&e.into()));
+            if let Some(report) = handler.borrow().clone() {
+                report(error);
+            }
+        });
+
+        Ok(Self { db, cache: RefCell::new(cache), error_handler, on_abort })
+    }
+}
+
+impl StateStore for IndexedDbStore {
+    fn get(&self, key: &str) -> Result<Option<String>, StoreError> {
+        Ok(self.cache.borrow().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: &str) -> Result<(), StoreError> {
+        self.cache.borrow_mut().insert(key.to_string(), value.to_string());
+        let tx = self.db
+            .transaction_with_str_and_mode(IDB_STORE, web_sys::IdbTransactionMode::Readwrite)
+            .map_err(|e| store_error(&e))?;
+        tx.set_onabort(Some(self.on_abort.as_ref().unchecked_ref()));
+        tx.object_store(IDB_STORE)
+            .and_then(|objects| objects.put_with_key(&JsValue::from_str(value), &JsValue::from_str(key)))
+            .map(|_| ())
+            .map_err(|e| store_error(&e))
+    }
+
+    fn on_async_error(&self, handler: Rc<dyn Fn(StoreError)>) {
+        *self.error_handler.borrow_mut() = Some(handler);
+    }
+}
+
+/// The store the app persists to, plus a problem to show the user if it had to fall back
+pub struct OpenedStore {
+    pub store: Rc<dyn StateStore>,
+    pub warning: Option<StoreError>,
+}
+
+/// localStorage if usable, else IndexedDB, else memory only (with a warning).
+/// A full localStorage is kept, since everything saved so far still reads back.
+/// Cross-tab sync relies on `storage` events, so it only works with localStorage.
+pub async fn open_store() -> OpenedStore {
+    if let Ok(local) = LocalStore::open() {
+        match local.probe() {
+            Err(StoreError::Unavailable) => {}
+            probed => return OpenedStore { store: Rc::new(local), warning: probed.err() },
+        }
+    }
+    match IndexedDbStore::open().await {
+        Ok(idb) => OpenedStore { store: Rc::new(idb), warning: None },
+        Err(_) => OpenedStore { store: Rc::new(MemoryStore::default()), warning: Some(StoreError::Unavailable) },
+    }
+}
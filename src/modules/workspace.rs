@@ -0,0 +1,273 @@
+use serde::{Deserialize, Serialize};
+use crate::modules::themes::ThemeName;
+use crate::modules::timezone_db::zone_by_id;
+use crate::modules::types::{ActiveTimezones, Meeting, ReferenceZone, TimezoneEntry, WORK_START, WORK_END};
+
+/// A named set of zones, working hours, meetings and theme, e.g. one per team.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Workspace {
+    pub id: u32,
+    pub name: String,
+    pub zones: ActiveTimezones,
+    pub meetings: Vec<Meeting>,
+    pub theme: ThemeName,
+    pub reference: ReferenceZone,
+}
+
+impl Workspace {
+    pub fn new(id: u32, name: impl Into<String>) -> Self {
+        Self {
+            id,
+            name: name.into(),
+            zones: ActiveTimezones::default(),
+            meetings: Vec::new(),
+            theme: ThemeName::Minimalist,
+            reference: ReferenceZone::FirstRing,
+        }
+    }
+}
+
+/// Id one higher than any existing workspace
+pub fn next_workspace_id(workspaces: &[Workspace]) -> u32 {
+    workspaces.iter().map(|w| w.id).max().map_or(1, |max| max + 1)
+}
+
+/// `base`, or `base 2`, `base 3`... whichever is not taken yet
+pub fn unique_workspace_name(workspaces: &[Workspace], base: &str) -> String {
+    let taken = |name: &str| workspaces.iter().any(|w| w.name == name);
+    if !taken(base) {
+        return base.to_string();
+    }
+    (2..).map(|n| format!("{} {}", base, n)).find(|name| !taken(name)).unwrap_or_default()
+}
+
+// Serialized shapes. Zones are stored by stable database id rather than by
+// value so renamed or re-offset database entries are picked up on load.
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ZoneRecord {
+    pub id: String,
+    #[serde(default = "default_work_start")]
+    pub work_start: f64,
+    #[serde(default = "default_work_end")]
+    pub work_end: f64,
+    /// Palette slot of the zone's ring; missing in saves from before slots were stored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeetingRecord {
+    pub id: u32,
+    pub utc_hour: u32,
+    pub title: String,
+    #[serde(default)]
+    pub essential: bool,
+    #[serde(default)]
+    pub organizer_zone: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct WorkspaceRecord {
+    id: u32,
+    name: String,
+    zones: Vec<ZoneRecord>,
+    #[serde(default)]
+    meetings: Vec<MeetingRecord>,
+    theme: String,
+    #[serde(default)]
+    reference: Option<String>,
+}
+
+fn default_work_start() -> f64 {
+    WORK_START
+}
+
+fn default_work_end() -> f64 {
+    WORK_END
+}
+
+impl ZoneRecord {
+    /// One record per ring, with its palette slot
+    pub fn from_zones(zones: &ActiveTimezones) -> Vec<Self> {
+        zones.zones().iter().enumerate().map(|(i, tz)| Self {
+            id: tz.id.to_string(),
+            work_start: tz.work_start,
+            work_end: tz.work_end,
+            color: Some(zones.palette_index(i)),
+        }).collect()
+    }
+
+    /// Resolve against the database; `None` for unknown ids
+    pub fn to_entry(&self) -> Option<TimezoneEntry> {
+        zone_by_id(&self.id).map(|tz| TimezoneEntry {
+            work_start: self.work_start,
+            work_end: self.work_end,
+            ..tz.clone()
+        })
+    }
+}
+
+impl From<&Meeting> for MeetingRecord {
+    fn from(m: &Meeting) -> Self {
+        Self {
+            id: m.id,
+            utc_hour: m.utc_hour,
+            title: m.title.clone(),
+            essential: m.essential,
+            organizer_zone: m.organizer_zone.to_string(),
+        }
+    }
+}
+
+impl MeetingRecord {
+    /// Convert back, falling back to `default_organizer` for unknown zone ids
+    pub fn to_meeting(&self, default_organizer: &'static str) -> Meeting {
+        Meeting {
+            id: self.id,
+            utc_hour: self.utc_hour % 24,
+            title: self.title.clone(),
+            essential: self.essential,
+            organizer_zone: zone_by_id(&self.organizer_zone).map_or(default_organizer, |tz| tz.id),
+        }
+    }
+}
+
+impl From<&Workspace> for WorkspaceRecord {
+    fn from(w: &Workspace) -> Self {
+        Self {
+            id: w.id,
+            name: w.name.clone(),
+            zones: ZoneRecord::from_zones(&w.zones),
+            meetings: w.meetings.iter().map(MeetingRecord::from).collect(),
+            theme: w.theme.key(),
+            reference: Some(w.reference.key().to_string()),
+        }
+    }
+}
+
+impl WorkspaceRecord {
+    fn into_workspace(self) -> Workspace {
+        let zones: Vec<(TimezoneEntry, Option<usize>)> = self.zones.iter()
+            .filter_map(|r| Some((r.to_entry()?, r.color)))
+            .collect();
+        let zones = if zones.is_empty() { ActiveTimezones::default() } else { ActiveTimezones::with_colors(zones) };
+        let default_organizer = zones.zones()[0].id;
+        Workspace {
+            id: self.id,
+            name: self.name,
+            meetings: self.meetings.iter().map(|m| m.to_meeting(default_organizer)).collect(),
+            zones,
+            theme: ThemeName::from_key(&self.theme).unwrap_or(ThemeName::Minimalist),
+            reference: self.reference.as_deref().and_then(ReferenceZone::from_key).unwrap_or_default(),
+        }
+    }
+}
+
+pub fn workspaces_to_json(workspaces: &[Workspace]) -> String {
+    let records: Vec<WorkspaceRecord> = workspaces.iter().map(WorkspaceRecord::from).collect();
+    serde_json::to_string(&records).unwrap_or_default()
+}
+
+/// Parse persisted workspaces. Unknown zones are dropped; returns `None`
+/// if the JSON is unreadable or holds no workspaces.
+pub fn workspaces_from_json(raw: &str) -> Option<Vec<Workspace>> {
+    let records: Vec<WorkspaceRecord> = serde_json::from_str(raw).ok()?;
+    let workspaces: Vec<Workspace> = records.into_iter().map(WorkspaceRecord::into_workspace).collect();
+    (!workspaces.is_empty()).then_some(workspaces)
+}
+
+/// Result of adopting another tab's saved workspaces.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RemoteWorkspaces {
+    pub workspaces: Vec<Workspace>,
+    /// This tab's active workspace, or the first one if the other tab deleted it
+    pub active: u32,
+    /// Set when the active workspace's contents changed and must be reloaded
+    pub reload: Option<Workspace>,
+}
+
+/// Reconcile workspaces saved by another tab into this one.
+///
+/// Every tab saves on each edit, so the stored list is always the most recent
+/// write and replaces the local one (last writer wins). Which workspace is
+/// active stays per tab. Returns `None` when nothing differs.
+pub fn reconcile_remote_workspaces(local: &[Workspace], active: u32, remote: Vec<Workspace>) -> Option<RemoteWorkspaces> {
+    if remote.is_empty() || remote == local {
+        return None;
+    }
+    let current = remote.iter().find(|w| w.id == active).unwrap_or(&remote[0]);
+    let reload = (current.id != active || local.iter().find(|w| w.id == active) != Some(current))
+        .then(|| current.clone());
+    Some(RemoteWorkspaces { active: current.id, reload, workspaces: remote })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut w = Workspace::new(3, "Platform team");
+        w.theme = ThemeName::Bold;
+        w.reference = ReferenceZone::Zone("europe_london");
+        w.zones.set_work_hours(1, 7.5, WORK_END);
+        // Ring colors are saved with the zones, not derived from their order
+        w.zones.move_zone(2, 0);
+        w.meetings.push(Meeting {
+            id: 42,
+            utc_hour: 15,
+            title: "Sync".to_string(),
+            essential: true,
+            organizer_zone: "europe_london",
+        });
+        let json = workspaces_to_json(std::slice::from_ref(&w));
+        assert_eq!(workspaces_from_json(&json), Some(vec![w]));
+    }
+
+    #[test]
+    fn test_unknown_ids_are_dropped() {
+        let json = r#"[{"id":1,"name":"A","theme":"Nope","zones":[{"id":"mars_olympus"},{"id":"asia_tokyo"}],
+            "meetings":[{"id":1,"utc_hour":25,"title":"x","organizer_zone":"mars_olympus"}]}]"#;
+        let ws = workspaces_from_json(json).unwrap();
+        assert_eq!(ws[0].zones.zones().len(), 1);
+        assert_eq!(ws[0].zones.zones()[0].id, "asia_tokyo");
+        assert_eq!(ws[0].zones.zones()[0].work_start, WORK_START);
+        assert_eq!(ws[0].meetings[0].organizer_zone, "asia_tokyo");
+        assert_eq!(ws[0].meetings[0].utc_hour, 1);
+        assert_eq!(ws[0].theme, ThemeName::Minimalist);
+        assert_eq!(workspaces_from_json("not json"), None);
+        assert_eq!(workspaces_from_json("[]"), None);
+    }
+
+    #[test]
+    fn test_names_and_ids() {
+        let ws = vec![Workspace::new(1, "Team"), Workspace::new(4, "Team 2")];
+        assert_eq!(next_workspace_id(&ws), 5);
+        assert_eq!(unique_workspace_name(&ws, "Team"), "Team 3");
+        assert_eq!(unique_workspace_name(&ws, "Other"), "Other");
+    }
+
+    #[test]
+    fn test_reconcile_remote() {
+        let local = vec![Workspace::new(1, "A"), Workspace::new(2, "B")];
+        assert_eq!(reconcile_remote_workspaces(&local, 2, local.clone()), None);
+
+        // Edits to another workspace are adopted without reloading the active one
+        let mut remote = local.clone();
+        remote[0].name = "Renamed".to_string();
+        let update = reconcile_remote_workspaces(&local, 2, remote.clone()).unwrap();
+        assert_eq!((update.active, update.reload), (2, None));
+        assert_eq!(update.workspaces, remote);
+
+        // Edits to the active workspace reload it
+        remote[1].theme = ThemeName::Bold;
+        let update = reconcile_remote_workspaces(&local, 2, remote.clone()).unwrap();
+        assert_eq!(update.reload, Some(remote[1].clone()));
+
+        // Deleting the active workspace falls back to the first
+        let update = reconcile_remote_workspaces(&local, 2, vec![local[0].clone()]).unwrap();
+        assert_eq!(update.active, 1);
+        assert_eq!(update.reload, Some(local[0].clone()));
+    }
+}
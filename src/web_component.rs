@@ -0,0 +1,166 @@
+//! `<tz-clock>` custom element wrapping the [`Clock`] component, for pages
+//! that are not built with Leptos:
+//!
+//! ```html
+//! <tz-clock zones="america_chicago,europe_london" theme="bold" mode="light"></tz-clock>
+//! ```
+//!
+//! Attribute changes are pushed into the clock's signals, and clicking a
+//! segment dispatches a `tz-slot-select` event whose `detail` mirrors
+//! [`SelectedSlot`]. The clock renders inside an open shadow root so host
+//! styles cannot leak in, apart from the `--tz-*` color variables, which
+//! the page can set on the element to override single theme colors.
+
+use leptos::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use crate::app::schedule_minute_update;
+use crate::components::Clock;
+use crate::modules::*;
+
+pub const TAG_NAME: &str = "tz-clock";
+pub const SLOT_SELECT_EVENT: &str = "tz-slot-select";
+
+const SHADOW_STYLES: &str = r#"
+:host { display: block; }
+svg path { transition: filter 0.15s ease; }
+svg path:hover { filter: brightness(0.95); }
+.drop-shadow-lg {
+  filter: drop-shadow(0 10px 8px rgb(0 0 0 / 0.04)) drop-shadow(0 4px 3px rgb(0 0 0 / 0.1));
+}
+"#;
+
+/// The class is defined in JS because custom elements must extend
+/// `HTMLElement`; each lifecycle callback forwards to Rust.
+const DEFINE_ELEMENT_JS: &str = r#"
+if (!customElements.get(tagName)) {
+  customElements.define(tagName, class extends HTMLElement {
+    static get observedAttributes() { return ['zones', 'theme', 'mode']; }
+    connectedCallback() {
+      if (!this.__tzClock) {
+        this.__tzClock = mount(this, this.attachShadow({ mode: 'open' }));
+      }
+    }
+    attributeChangedCallback(name, _old, value) {
+      if (this.__tzClock) this.__tzClock(name, value);
+    }
+  });
+}
+"#;
+
+/// Returns true when the host page opted out of mounting the full app,
+/// via `<body data-tz-clock="elements">`, and only wants the element.
+pub fn elements_only() -> bool {
+    web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.body())
+        .and_then(|b| b.get_attribute("data-tz-clock"))
+        .is_some_and(|v| v == "elements")
+}
+
+/// Define `<tz-clock>` on the current page (idempotent).
+pub fn register() {
+    let mount = Closure::<dyn FnMut(web_sys::HtmlElement, web_sys::ShadowRoot) -> JsValue>::new(mount_element);
+    let define = js_sys::Function::new_with_args("tagName, mount", DEFINE_ELEMENT_JS);
+    let _ = define.call2(&JsValue::NULL, &JsValue::from_str(TAG_NAME), mount.as_ref());
+    mount.forget();
+}
+
+/// Mount a clock into the element's shadow root and return the callback
+/// the JS class uses to forward attribute changes.
+fn mount_element(host: web_sys::HtmlElement, shadow: web_sys::ShadowRoot) -> JsValue {
+    let Some(document) = web_sys::window().and_then(|w| w.document()) else {
+        return JsValue::UNDEFINED;
+    };
+
+    let initial_zones = host.get_attribute("zones").map(|z| parse_zone_ids(&z)).unwrap_or_default();
+    let (active_zones, set_active_zones) = signal(if initial_zones.is_empty() {
+        ActiveTimezones::default()
+    } else {
+        ActiveTimezones::new(initial_zones)
+    });
+    let (theme_name, set_theme_name) = signal(
+        host.get_attribute("theme").and_then(|v| ThemeName::from_label(&v)).unwrap_or(ThemeName::Minimalist),
+    );
+    let (mode, set_mode) = signal(
+        host.get_attribute("mode").and_then(|v| Mode::from_label(&v)).unwrap_or(Mode::Dark),
+    );
+    let (meetings, _) = signal(Vec::<Meeting>::new());
+    let (selected_slot, set_selected_slot) = signal(None::<SelectedSlot>);
+    let (current_utc, set_current_utc) = signal(get_current_utc_hour());
+
+    schedule_minute_update(set_current_utc);
+
+    if let Ok(style) = document.create_element("style") {
+        style.set_text_content(Some(SHADOW_STYLES));
+        let _ = shadow.append_child(&style);
+    }
+    // Color variables on :host, where the host page's rules for the element win
+    let Ok(theme_style) = document.create_element("style") else {
+        return JsValue::UNDEFINED;
+    };
+    let _ = shadow.append_child(&theme_style);
+    let Ok(container) = document.create_element("div").map(JsCast::unchecked_into::<web_sys::HtmlElement>) else {
+        return JsValue::UNDEFINED;
+    };
+    let _ = shadow.append_child(&container);
+
+    let handle = leptos::mount::mount_to(container, move || {
+        Effect::new(move || {
+            let colors = get_theme(theme_name.get(), mode.get());
+            theme_style.set_text_content(Some(&theme_stylesheet(colors, ":host")));
+        });
+        Effect::new(move || {
+            let Some(slot) = selected_slot.get() else { return };
+            let zones = active_zones.get_untracked();
+            dispatch_slot_select(&host, &slot, &zones);
+        });
+
+        view! {
+            <Clock
+                meetings=meetings
+                set_selected_slot=set_selected_slot
+                current_utc=current_utc
+                active_zones=active_zones
+            />
+        }
+    });
+    std::mem::forget(handle);
+
+    let on_attribute = Closure::<dyn FnMut(String, Option<String>)>::new(move |name: String, value: Option<String>| {
+        match name.as_str() {
+            "zones" => {
+                let zones = value.map(|v| parse_zone_ids(&v)).unwrap_or_default();
+                set_active_zones.set(if zones.is_empty() {
+                    ActiveTimezones::default()
+                } else {
+                    ActiveTimezones::new(zones)
+                });
+                set_selected_slot.set(None);
+            }
+            "theme" => set_theme_name.set(value.and_then(|v| ThemeName::from_label(&v)).unwrap_or(ThemeName::Minimalist)),
+            "mode" => set_mode.set(value.and_then(|v| Mode::from_label(&v)).unwrap_or(Mode::Dark)),
+            _ => {}
+        }
+    });
+    on_attribute.into_js_value()
+}
+
+/// Fire `tz-slot-select` with `{ utcHour, localHours, zones }` as detail.
+/// The event bubbles and is composed so it escapes the shadow root.
+fn dispatch_slot_select(host: &web_sys::HtmlElement, slot: &SelectedSlot, zones: &ActiveTimezones) {
+    let detail = js_sys::Object::new();
+    let local_hours: js_sys::Array = slot.local_hours.iter().map(|h| JsValue::from_f64(*h)).collect();
+    let zone_ids: js_sys::Array = zones.zones().iter().map(|z| JsValue::from_str(z.id)).collect();
+    let _ = js_sys::Reflect::set(&detail, &"utcHour".into(), &JsValue::from(slot.utc_hour));
+    let _ = js_sys::Reflect::set(&detail, &"localHours".into(), &local_hours);
+    let _ = js_sys::Reflect::set(&detail, &"zones".into(), &zone_ids);
+
+    let init = web_sys::CustomEventInit::new();
+    init.set_bubbles(true);
+    init.set_composed(true);
+    init.set_detail(&detail);
+    if let Ok(event) = web_sys::CustomEvent::new_with_event_init_dict(SLOT_SELECT_EVENT, &init) {
+        let _ = host.dispatch_event(&event);
+    }
+}